@@ -1,6 +1,8 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use masuda::filter::{Filter, StatComparison, StatFilter};
 use masuda::generators::Generator;
-use masuda::generators::LinearCongruential;
+use masuda::generators::{LcrngBatch, LinearCongruential, BATCH_LANES};
+use masuda::Profile;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let mut lcrng = LinearCongruential::new(0);
@@ -8,10 +10,46 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("LCRNG Method 1", |b| {
         b.iter(|| {
             for _ in 0..1000 {
-                lcrng.method_1();
+                black_box(lcrng.method_1());
             }
         })
     });
+
+    // a filter which fewer than one frame in a hundred passes, as when hunting for a spread
+    let filter = Filter::new(&Profile::new(0, 0))
+        .with_stat(StatFilter::HP(StatComparison::GreaterThan(25)))
+        .with_stat(StatFilter::Attack(StatComparison::GreaterThan(25)))
+        .with_stat(StatFilter::Speed(StatComparison::GreaterThan(25)));
+    let half_words = filter.iv_half_words();
+    let mut lcrng = LinearCongruential::new(0);
+
+    c.bench_function("LCRNG Method 1 IV search", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                let pokemon = lcrng.method_1();
+                if filter.matches_ivs(&pokemon.ivs) {
+                    black_box(pokemon);
+                }
+            }
+        })
+    });
+
+    let mut batch = LcrngBatch::from_frames(0);
+
+    c.bench_function("LCRNG Method 1 IV search batch", |b| {
+        b.iter(|| {
+            for _ in 0..1000 / BATCH_LANES {
+                for (_, pokemon) in batch.method_1_matching(&half_words) {
+                    black_box(pokemon);
+                }
+                batch.next_batch();
+            }
+        })
+    });
+
+    let mut rng = LinearCongruential::new(0);
+
+    c.bench_function("LCRNG jump ahead", |b| b.iter(|| rng.jump(black_box(1000))));
 }
 
 criterion_group!(benches, criterion_benchmark);
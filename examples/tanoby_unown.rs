@@ -0,0 +1,23 @@
+extern crate masuda;
+
+use masuda::generators::{Game, Method, TanobyChamber};
+use masuda::Filter;
+use masuda::Profile;
+use masuda::Searcher;
+
+fn main() {
+    let mut searcher = Searcher::new(
+        Game::FireRed,
+        Method::TanobyH1(TanobyChamber::Liptoo),
+        1000000,
+    );
+
+    let profile = Profile::new(10101, 12345);
+    let filter = Filter::new(&profile).shiny();
+
+    for result in searcher.search(Some(filter)) {
+        if result.pokemon.get_unown_form() == 'U' {
+            println!("{:?}", result);
+        }
+    }
+}
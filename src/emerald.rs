@@ -0,0 +1,121 @@
+use crate::generators::LinearCongruential;
+use crate::pokemon::Pokemon;
+
+/// Number of Feebas fishing spots active on Route 119 at once
+pub const FEEBAS_SPOTS: usize = 6;
+/// Number of fishable water tiles on Route 119, which Feebas spots are numbered among starting from 1
+pub const ROUTE_119_WATER_TILES: u16 = 447;
+
+/// Advances a value once through the secondary lcrng, which Emerald uses for the values it updates once a day
+fn daily_rng(value: u32) -> u32 {
+    LinearCongruential::new_gen3_secondary(value).next_u32()
+}
+
+/// Predicts the Lottery Corner number drawn at the day change.
+///
+/// The number starts from a 16-bit number of the main rng and is advanced through the daily rng once per day that passed,
+/// and the ticket only shows its low 16 bits. The winning prize depends on how many trailing digits match the trainer id.
+pub fn lottery_number(rand: u16, days: u16) -> u16 {
+    let mut value = rand as u32;
+    for _ in 0..days {
+        value = daily_rng(value);
+    }
+    value as u16
+}
+
+/// Predicts the Mirage Island value after the given number of days, starting from the value currently in the save.
+///
+/// The value is advanced through the daily rng once per day that passed.
+pub fn mirage_island_value(current: u32, days: u16) -> u32 {
+    let mut value = current;
+    for _ in 0..days {
+        value = daily_rng(value);
+    }
+    value
+}
+
+/// Mirage Island is visible when the low half of the PID of any pokemon in the party matches the high half of the Mirage Island value
+pub fn is_mirage_island_visible(value: u32, party: &[Pokemon]) -> bool {
+    party
+        .iter()
+        .any(|pokemon| pokemon.pid as u16 == (value >> 16) as u16)
+}
+
+/// Finds the first day, within the given number of days, on which Mirage Island becomes visible for the party
+pub fn days_until_mirage_island(current: u32, party: &[Pokemon], max_days: u16) -> Option<u16> {
+    let mut value = current;
+    for day in 0..=max_days {
+        if is_mirage_island_visible(value, party) {
+            return Some(day);
+        }
+        value = daily_rng(value);
+    }
+    None
+}
+
+/// Computes the Route 119 water tiles on which Feebas can be fished, from the Feebas seed stored with the Dewford trends.
+///
+/// The seed starts the daily rng, and the high half of each output picks a tile modulo 447, where 0 stands for the last tile.
+/// Tiles 1 to 3 lie under the bridge and cannot be fished, so they are skipped. Tiles are numbered in the game's order,
+/// scanning each section of the route row by row.
+pub fn feebas_tiles(seed: u16) -> [u16; FEEBAS_SPOTS] {
+    let mut value = seed as u32;
+    let mut tiles = [0u16; FEEBAS_SPOTS];
+    let mut i = 0;
+
+    while i < FEEBAS_SPOTS {
+        value = daily_rng(value);
+        let tile = match (value >> 16) as u16 % ROUTE_119_WATER_TILES {
+            0 => ROUTE_119_WATER_TILES,
+            tile => tile,
+        };
+        if tile >= 4 {
+            tiles[i] = tile;
+            i += 1;
+        }
+    }
+
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::IndividualValues;
+
+    #[test]
+    fn test_daily_values() -> Result<(), String> {
+        assert_eq!(lottery_number(0x1234, 0), 0x1234);
+        assert_eq!(lottery_number(1, 1), 0x4E6D + 0x3039);
+        assert_eq!(mirage_island_value(0, 2), daily_rng(0x3039));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mirage_island() -> Result<(), String> {
+        let value = mirage_island_value(0xDEADBEEF, 3);
+        let party = [Pokemon::new(
+            0xFFFF0000 | (value >> 16),
+            IndividualValues::default(),
+        )];
+        assert!(is_mirage_island_visible(value, &party));
+        assert_eq!(days_until_mirage_island(0xDEADBEEF, &party, 10), Some(3));
+        assert_eq!(days_until_mirage_island(0xDEADBEEF, &party, 2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_feebas_tiles() -> Result<(), String> {
+        // the first output of seed 0 is 0x3039, whose high half of 0 stands for the last tile
+        assert_eq!(feebas_tiles(0), [447, 149, 291, 351, 369, 78]);
+        assert_eq!(feebas_tiles(0x1234), [247, 306, 425, 132, 230, 377]);
+
+        for seed in 0..1000 {
+            let tiles = feebas_tiles(seed);
+            assert!(tiles
+                .iter()
+                .all(|tile| (4..=ROUTE_119_WATER_TILES).contains(tile)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::generators::{GenerationMethod, LinearCongruential, Method};
+use crate::pokemon::Pokemon;
+
+/// Gen 3 event distributions whose PID and IVs are generated from a single seed, rather than from frames of the main rng
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    /// The Jirachi from the Colosseum bonus disc. Its seed is derived from the checksum of the save file, so it only spans 16 bits.
+    Wishmaker,
+    /// The Celebi from the Japanese Colosseum bonus disc
+    AgetoCelebi,
+    /// Pokemon distributed at the Pokemon Center New York
+    Pcny,
+    /// Eggs received through Mystery Gift
+    MysteryGiftEgg,
+}
+
+/// The order in which the two halves of the PID are drawn from the rng
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PidOrder {
+    /// The first call becomes the low half of the PID, as in Method 1
+    Standard,
+    /// The first call becomes the high half of the PID
+    Reverse,
+}
+
+/// The range of seeds a distribution can start from
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SeedRange {
+    /// Only seeds between 0x0000 and 0xFFFF are reachable
+    Restricted,
+    /// Any 32-bit seed is reachable
+    Unrestricted,
+}
+
+impl SeedRange {
+    fn max_seed(&self) -> u32 {
+        match self {
+            SeedRange::Restricted => 0xFFFF,
+            SeedRange::Unrestricted => u32::MAX,
+        }
+    }
+}
+
+impl Event {
+    pub fn pid_order(&self) -> PidOrder {
+        match self {
+            Event::AgetoCelebi => PidOrder::Standard,
+            Event::Wishmaker | Event::Pcny | Event::MysteryGiftEgg => PidOrder::Reverse,
+        }
+    }
+
+    pub fn seed_range(&self) -> SeedRange {
+        match self {
+            Event::Wishmaker | Event::Pcny => SeedRange::Restricted,
+            Event::AgetoCelebi | Event::MysteryGiftEgg => SeedRange::Unrestricted,
+        }
+    }
+
+    /// The generation method of the event, for searching its frames or seeds
+    pub fn method(&self) -> Method {
+        Method::Event(self.pid_order(), self.seed_range())
+    }
+
+    /// Four RNG calls are made starting from the given seed, two to generate the PID and two to generate the IVs.
+    /// It can be illustrated as [PID] [PID] [IVs] [IVs], where the order of the PID halves depends on the event.
+    pub fn generate(&self, seed: u32) -> Pokemon {
+        // event methods have no encounter to miss
+        self.method()
+            .generate(&mut LinearCongruential::new(seed))
+            .unwrap()
+            .pokemon
+    }
+
+    /// Enumerates every spread the event can legally have, one for each reachable seed.
+    ///
+    /// Restricted events yield 65536 spreads, while unrestricted events walk the full 32-bit seed space.
+    pub fn spreads(&self) -> impl Iterator<Item = EventSpread> {
+        let event = *self;
+        (0..=self.seed_range().max_seed()).map(move |seed| EventSpread {
+            seed,
+            pokemon: event.generate(seed),
+        })
+    }
+}
+
+pub struct EventSpread {
+    pub seed: u32,
+    pub pokemon: Pokemon,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::IndividualValues;
+
+    #[test]
+    fn test_reverse_order_swaps_pid_halves() -> Result<(), String> {
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        let standard_pid = lcrng.generate_pid();
+
+        let pokemon = Event::Wishmaker.generate(0x1A56B091u32);
+        assert_eq!(pokemon.pid, standard_pid.rotate_left(16));
+        assert_eq!(pokemon.pid, 0x01DB7B06u32);
+        assert_eq!(pokemon.ivs, IndividualValues::new(19, 17, 20, 3, 25, 16));
+        Ok(())
+    }
+
+    #[test]
+    fn test_restricted_spreads() -> Result<(), String> {
+        assert_eq!(Event::Wishmaker.spreads().count(), 0x10000);
+        assert_eq!(Event::Pcny.spreads().last().unwrap().seed, 0xFFFF);
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::pokemon::{IndividualValues, Nature, Pokemon};
+use crate::pokemon::{IndividualValues, Nature, Pokemon, SpindaSpots, WurmpleEvolution};
 use crate::Profile;
 
 use std::collections::HashSet;
@@ -9,6 +9,10 @@ pub struct Filter {
     shiny: bool,
     stat_filters: StatFilters,
     nature_filter: NatureFilter,
+    unown_filter: UnownFilter,
+    wurmple_evolution: Option<WurmpleEvolution>,
+    spinda_spots: Option<SpindaSpots>,
+    characteristic: Option<&'static str>,
 }
 
 impl Filter {
@@ -19,6 +23,10 @@ impl Filter {
             sid: profile.sid,
             stat_filters: StatFilters::new(),
             nature_filter: NatureFilter::Any,
+            unown_filter: UnownFilter::Any,
+            wurmple_evolution: None,
+            spinda_spots: None,
+            characteristic: None,
         }
     }
 
@@ -46,6 +54,75 @@ impl Filter {
         self
     }
 
+    pub fn with_unown_form(mut self, letter: char) -> Self {
+        match self.unown_filter {
+            UnownFilter::Any => {
+                let mut letters = HashSet::new();
+                letters.insert(letter);
+                self.unown_filter = UnownFilter::Letters(letters);
+            }
+            UnownFilter::Letters(ref mut letters) => {
+                letters.insert(letter);
+            }
+        }
+        self
+    }
+
+    pub fn with_wurmple_evolution(mut self, evolution: WurmpleEvolution) -> Self {
+        self.wurmple_evolution = Some(evolution);
+        self
+    }
+
+    pub fn with_spinda_spots(mut self, spots: SpindaSpots) -> Self {
+        self.spinda_spots = Some(spots);
+        self
+    }
+
+    pub fn with_characteristic(mut self, characteristic: &'static str) -> Self {
+        self.characteristic = Some(characteristic);
+        self
+    }
+
+    /// Checks only the stat filters, for IVs generated separately from any PID
+    pub fn matches_ivs(&self, ivs: &IndividualValues) -> bool {
+        self.stat_filters.matches(ivs)
+    }
+
+    /// Decomposes the stat filters into the IVs each of them allows, in the order HP, Atk, Def, Spe, SpA, SpD,
+    /// as drawn by methods taking every IV from its own rng call. Each IV is allowed when its entry is true.
+    pub fn allowed_ivs(&self) -> [[bool; 32]; 6] {
+        let filters = &self.stat_filters;
+        [
+            &filters.hp,
+            &filters.atk,
+            &filters.def,
+            &filters.spe,
+            &filters.spa,
+            &filters.spd,
+        ]
+        .map(|filter| std::array::from_fn(|iv| filter.matches(iv as u8)))
+    }
+
+    /// Decomposes the stat filters into the 15-bit IV half-words they allow, the first holding HP, Atk and Def and the second Spe, SpA and SpD,
+    /// as drawn by the two IV calls of the Gen 3 methods. Each half-word is allowed when its entry is true.
+    pub fn iv_half_words(&self) -> [Vec<bool>; 2] {
+        let filters = &self.stat_filters;
+        let allowed = |low: &StatFilter, mid: &StatFilter, high: &StatFilter| -> Vec<bool> {
+            (0..0x8000u16)
+                .map(|half_word| {
+                    low.matches((half_word & 0x1F) as u8)
+                        && mid.matches(((half_word >> 5) & 0x1F) as u8)
+                        && high.matches(((half_word >> 10) & 0x1F) as u8)
+                })
+                .collect()
+        };
+
+        [
+            allowed(&filters.hp, &filters.atk, &filters.def),
+            allowed(&filters.spe, &filters.spa, &filters.spd),
+        ]
+    }
+
     pub fn matches(&self, p: &Pokemon) -> bool {
         if !self.stat_filters.matches(&p.ivs) {
             return false;
@@ -58,6 +135,28 @@ impl Filter {
         if self.shiny && !p.get_shininess(self.tid, self.sid) {
             return false;
         }
+
+        if !self.unown_filter.matches(p.get_unown_form()) {
+            return false;
+        }
+
+        if let Some(evolution) = self.wurmple_evolution {
+            if p.get_wurmple_evolution() != evolution {
+                return false;
+            }
+        }
+
+        if let Some(spots) = self.spinda_spots {
+            if p.get_spinda_spots() != spots {
+                return false;
+            }
+        }
+
+        if let Some(characteristic) = self.characteristic {
+            if p.get_characteristic() != characteristic {
+                return false;
+            }
+        }
         true
     }
 }
@@ -76,6 +175,20 @@ impl NatureFilter {
     }
 }
 
+pub enum UnownFilter {
+    Any,
+    Letters(HashSet<char>),
+}
+
+impl UnownFilter {
+    fn matches(&self, letter: char) -> bool {
+        match self {
+            UnownFilter::Any => true,
+            UnownFilter::Letters(letters) => letters.contains(&letter),
+        }
+    }
+}
+
 pub struct StatFilters {
     hp: StatFilter,
     atk: StatFilter,
@@ -0,0 +1,87 @@
+/// How the encounter is started, which decides whether an encounter check is made before the pokemon is generated
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EncounterTrigger {
+    /// Sweet Scent, the Rock Smash or fishing prompts and static encounters start the encounter without an encounter check
+    SweetScent,
+    /// Walking in grass makes one RNG call for the encounter rate check before the pokemon is generated
+    Walking,
+}
+
+/// Maps the in-game frames shown by a timer to the number of RNG advances made by then.
+///
+/// Most of the time the RNG advances once per frame, but some screens advance it more often,
+/// such as Emerald's battle animations which advance it twice per frame, and some maps advance it on every step taken.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrameAdvancement {
+    advances_per_frame: usize,
+    steps: usize,
+    advances_per_step: usize,
+    trigger: EncounterTrigger,
+}
+
+impl FrameAdvancement {
+    /// One advance per frame, with the encounter started by Sweet Scent
+    pub fn new() -> Self {
+        FrameAdvancement {
+            advances_per_frame: 1,
+            steps: 0,
+            advances_per_step: 0,
+            trigger: EncounterTrigger::SweetScent,
+        }
+    }
+
+    /// Two advances per frame, as while Emerald plays battle animations
+    pub fn emerald_battle() -> Self {
+        Self::new().with_advances_per_frame(2)
+    }
+
+    /// Sets the number of advances made every frame, which must be at least 1
+    pub fn with_advances_per_frame(mut self, advances: usize) -> Self {
+        self.advances_per_frame = advances.max(1);
+        self
+    }
+
+    /// Adds the advances made by the steps taken on a map which advances the RNG on every step
+    pub fn with_steps(mut self, steps: usize, advances_per_step: usize) -> Self {
+        self.steps = steps;
+        self.advances_per_step = advances_per_step;
+        self
+    }
+
+    pub fn with_trigger(mut self, trigger: EncounterTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// The number of RNG advances made before the pokemon of the given in-game frame is generated
+    pub fn advances(&self, frame: usize) -> usize {
+        let encounter_check = match self.trigger {
+            EncounterTrigger::SweetScent => 0,
+            EncounterTrigger::Walking => 1,
+        };
+        frame * self.advances_per_frame + self.steps * self.advances_per_step + encounter_check
+    }
+}
+
+impl Default for FrameAdvancement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advances() -> Result<(), String> {
+        assert_eq!(FrameAdvancement::new().advances(100), 100);
+        assert_eq!(FrameAdvancement::emerald_battle().advances(100), 200);
+
+        let walking = FrameAdvancement::new()
+            .with_steps(3, 2)
+            .with_trigger(EncounterTrigger::Walking);
+        assert_eq!(walking.advances(100), 107);
+        Ok(())
+    }
+}
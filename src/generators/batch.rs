@@ -0,0 +1,214 @@
+use crate::generators::lcrng::jump_constants;
+use crate::generators::LinearCongruential;
+use crate::pokemon::{IndividualValues, Pokemon};
+
+/// Number of frames or seeds evaluated together by a batch
+pub const BATCH_LANES: usize = 8;
+
+const MULTIPLIER: u32 = 0x41C64E6D;
+const INCREMENT: u32 = 0x6073;
+
+/// Evaluates the LCRNG over several frames or seeds at once, for brute force searches.
+///
+/// Every lane holds its own seed and is advanced by the same plain arithmetic on fixed size arrays, which the compiler turns into SIMD instructions.
+/// Moving on to the next batch of frames uses a precomputed jump ahead stride rather than stepping each lane.
+pub struct LcrngBatch {
+    seeds: [u32; BATCH_LANES],
+    multiplier: u32,
+    increment: u32,
+    stride_multiplier: u32,
+    stride_increment: u32,
+}
+
+impl LcrngBatch {
+    /// Constructs a batch over consecutive frames from the given seed, the first lane holding the seed itself
+    pub fn from_frames(initial_seed: u32) -> Self {
+        let mut seeds = [0u32; BATCH_LANES];
+        for (lane, seed) in seeds.iter_mut().enumerate() {
+            let (multiplier, increment) = jump_constants(MULTIPLIER, INCREMENT, lane as u64);
+            *seed = initial_seed
+                .wrapping_mul(multiplier)
+                .wrapping_add(increment);
+        }
+        let (stride_multiplier, stride_increment) =
+            jump_constants(MULTIPLIER, INCREMENT, BATCH_LANES as u64);
+
+        LcrngBatch {
+            seeds,
+            multiplier: MULTIPLIER,
+            increment: INCREMENT,
+            stride_multiplier,
+            stride_increment,
+        }
+    }
+
+    /// Constructs a batch over consecutive seeds starting from the given one, each lane at frame 0 of its seed
+    pub fn from_seeds(first_seed: u32) -> Self {
+        Self::from_seeds_of(&LinearCongruential::new(0), first_seed)
+    }
+
+    /// Constructs a batch over consecutive seeds starting from the given one, stepping the lanes like the given lcrng rather than the LCRNG,
+    /// such as the XDRNG of the GameCube games
+    pub fn from_seeds_of(rng: &LinearCongruential, first_seed: u32) -> Self {
+        let mut seeds = [0u32; BATCH_LANES];
+        for (lane, seed) in seeds.iter_mut().enumerate() {
+            *seed = first_seed.wrapping_add(lane as u32);
+        }
+        let (multiplier, increment) = rng.constants();
+
+        LcrngBatch {
+            seeds,
+            multiplier,
+            increment,
+            stride_multiplier: 1,
+            stride_increment: BATCH_LANES as u32,
+        }
+    }
+
+    /// The seed of every lane
+    pub fn seeds(&self) -> [u32; BATCH_LANES] {
+        self.seeds
+    }
+
+    /// Moves every lane on to the next batch, the following frames or the following seeds depending on how the batch was constructed
+    pub fn next_batch(&mut self) {
+        for seed in self.seeds.iter_mut() {
+            *seed = seed
+                .wrapping_mul(self.stride_multiplier)
+                .wrapping_add(self.stride_increment);
+        }
+    }
+
+    /// Draws the random number the given number of calls ahead of every lane, without moving the lanes
+    pub fn rands_ahead(&self, calls: u64) -> [u16; BATCH_LANES] {
+        let (multiplier, increment) = jump_constants(self.multiplier, self.increment, calls);
+        let mut rands = [0u16; BATCH_LANES];
+        for (seed, rand) in self.seeds.iter().zip(rands.iter_mut()) {
+            *rand = (seed.wrapping_mul(multiplier).wrapping_add(increment) >> 16) as u16;
+        }
+        rands
+    }
+
+    /// Generates the Method 1 pokemon of every lane without moving the lanes, in the same layout as `Generator::method_1`
+    #[inline]
+    pub fn method_1(&self) -> [Pokemon; BATCH_LANES] {
+        let [pid_low, pid_high, iv1, iv2] = self.method_1_calls();
+        std::array::from_fn(|lane| {
+            let pid = (pid_high[lane] as u32) << 16 | pid_low[lane] as u32;
+            let ivs = IndividualValues::new_from_numbers(iv1[lane], iv2[lane]);
+            Pokemon::new(pid, ivs)
+        })
+    }
+
+    /// Generates the Method 1 pokemon of the lanes whose IV half-words are allowed, as decomposed by `Filter::iv_half_words`, along with their lane.
+    /// The IVs of every lane are checked first, so no pokemon is built for the lanes which fail.
+    pub fn method_1_matching(
+        &self,
+        iv_half_words: &[Vec<bool>; 2],
+    ) -> impl Iterator<Item = (usize, Pokemon)> {
+        let [pid_low, pid_high, iv1, iv2] = self.method_1_calls();
+        let mut matching = 0u32;
+        for lane in 0..BATCH_LANES {
+            let allowed = iv_half_words[0][(iv1[lane] & 0x7FFF) as usize]
+                & iv_half_words[1][(iv2[lane] & 0x7FFF) as usize];
+            matching |= (allowed as u32) << lane;
+        }
+
+        (0..BATCH_LANES)
+            .filter(move |lane| matching & (1 << lane) != 0)
+            .map(move |lane| {
+                let pid = (pid_high[lane] as u32) << 16 | pid_low[lane] as u32;
+                let ivs = IndividualValues::new_from_numbers(iv1[lane], iv2[lane]);
+                (lane, Pokemon::new(pid, ivs))
+            })
+    }
+
+    /// The four random numbers drawn by Method 1 in every lane, call by call
+    #[inline]
+    fn method_1_calls(&self) -> [[u16; BATCH_LANES]; 4] {
+        let mut states = self.seeds;
+        let mut calls = [[0u16; BATCH_LANES]; 4];
+        for call in calls.iter_mut() {
+            for (state, rand) in states.iter_mut().zip(call.iter_mut()) {
+                *state = state
+                    .wrapping_mul(self.multiplier)
+                    .wrapping_add(self.increment);
+                *rand = (*state >> 16) as u16;
+            }
+        }
+        calls
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Generator, LinearCongruential};
+
+    #[test]
+    fn test_batch_matches_method_1() -> Result<(), String> {
+        let mut rng = LinearCongruential::new(0x5A0);
+        let mut batch = LcrngBatch::from_frames(0x5A0);
+
+        for _ in 0..4 {
+            for pokemon in batch.method_1().iter() {
+                let expected = rng.method_1();
+                assert_eq!(pokemon.pid, expected.pid);
+                assert!(pokemon.ivs == expected.ivs);
+            }
+            batch.next_batch();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_iv_precheck() -> Result<(), String> {
+        use crate::filter::{StatComparison, StatFilter};
+        use crate::{Filter, Profile};
+
+        let filter = Filter::new(&Profile::new(0, 0))
+            .with_stat(StatFilter::HP(StatComparison::GreaterThan(20)))
+            .with_stat(StatFilter::Speed(StatComparison::GreaterThan(15)));
+        let half_words = filter.iv_half_words();
+
+        let mut rng = LinearCongruential::new(0x5A0);
+        let mut batch = LcrngBatch::from_frames(0x5A0);
+        for _ in 0..64 {
+            let mut matching = batch.method_1_matching(&half_words).peekable();
+            for lane in 0..BATCH_LANES {
+                let expected = rng.method_1();
+                match matching.next_if(|(matching_lane, _)| *matching_lane == lane) {
+                    Some((_, pokemon)) => {
+                        assert_eq!(pokemon.pid, expected.pid);
+                        assert!(filter.matches_ivs(&pokemon.ivs));
+                    }
+                    None => assert!(!filter.matches_ivs(&expected.ivs)),
+                }
+            }
+            batch.next_batch();
+        }
+
+        let ahead = batch.rands_ahead(3);
+        for (lane, seed) in batch.seeds().iter().enumerate() {
+            let mut rng = LinearCongruential::new(*seed);
+            rng.jump(2);
+            assert_eq!(ahead[lane], rng.next_u16());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_over_seeds() -> Result<(), String> {
+        let mut batch = LcrngBatch::from_seeds(100);
+        batch.next_batch();
+        let seeds = batch.seeds();
+        let pokemon = batch.method_1();
+
+        for lane in 0..BATCH_LANES {
+            assert_eq!(seeds[lane], 100 + (BATCH_LANES + lane) as u32);
+            let expected = LinearCongruential::new(seeds[lane]).method_1();
+            assert_eq!(pokemon[lane].pid, expected.pid);
+        }
+        Ok(())
+    }
+}
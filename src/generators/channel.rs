@@ -0,0 +1,150 @@
+use crate::generators::{LinearCongruential, Rng};
+use crate::pokemon::{IndividualValues, Pokemon};
+
+/// The trainer id shared by every Jirachi distributed through Pokemon Channel
+pub const CHANNEL_TID: u16 = 40122;
+
+/// A Jirachi generated by Pokemon Channel, along with the secret id the game rolled for it
+pub struct ChannelJirachi {
+    pub pokemon: Pokemon,
+    pub sid: u16,
+}
+
+/// Generates Jirachi using the method Pokemon Channel uses, which runs on the XDRNG
+///
+/// Twelve RNG calls are made per Jirachi. It can be illustrated as
+/// [SID] [PID] [PID] [xxxx] [xxxx] [xxxx] [HP] [Atk] [Def] [Spe] [SpA] [SpD].
+/// The three unused calls roll the berry, game of origin and OT gender, and each IV is taken from the top 5 bits of its own call.
+pub struct Channel {
+    rng: LinearCongruential,
+}
+
+impl Channel {
+    /// Constructs a new Channel generator with the given initial seed
+    pub fn new(initial_seed: u32) -> Self {
+        Channel {
+            rng: LinearCongruential::new_xd(initial_seed),
+        }
+    }
+
+    /// Generates the Jirachi for the current frame, then advances the rng by a single frame
+    pub fn generate(&mut self) -> ChannelJirachi {
+        let og_seed = self.rng.seed();
+        let jirachi = channel_jirachi(&mut self.rng);
+
+        self.rng.set_seed(og_seed);
+        self.rng.step();
+
+        jirachi
+    }
+
+    /// Returns whether the given Jirachi could have been produced by Pokemon Channel
+    pub fn is_legal(pokemon: &Pokemon) -> bool {
+        !Self::seeds_for(pokemon).is_empty()
+    }
+
+    /// Finds every seed which generates the given PID and IVs with the Channel method.
+    ///
+    /// The low half of the PID is the top 16 bits of the third rng state, so only the bottom 16 bits of that state need to be searched.
+    /// Each candidate is stepped forward to verify the IVs, then backwards to recover the SID and the high half of the PID.
+    pub fn seeds_for(pokemon: &Pokemon) -> Vec<u32> {
+        let low = (pokemon.pid & 0xFFFF) as u16;
+        let high = (pokemon.pid >> 16) as u16;
+        let mut seeds = Vec::new();
+
+        for bottom in 0..=0xFFFFu32 {
+            let pid_seed = ((low as u32) << 16) | bottom;
+            let mut rng = LinearCongruential::new_xd(pid_seed);
+
+            for _ in 0..3 {
+                rng.step();
+            }
+            if channel_ivs(&mut rng) != pokemon.ivs {
+                continue;
+            }
+
+            rng.set_seed(pid_seed);
+            let high_roll = (rng.prev_u32() >> 16) as u16;
+            let sid = (rng.prev_u32() >> 16) as u16;
+            if channel_pid_high(high_roll, low, sid) != high {
+                continue;
+            }
+
+            seeds.push(rng.prev_u32());
+        }
+
+        seeds
+    }
+}
+
+/// The game flips the top bit of the high half of the PID unless the halves compare equal to the expression below.
+///
+/// This is intended to keep Jirachi from being shiny, but as the right hand side is a full 16-bit value the flip happens almost every time.
+fn channel_pid_high(high: u16, low: u16, sid: u16) -> u16 {
+    let expected = if low > 7 { 0 } else { 1 };
+    if expected != high ^ CHANNEL_TID ^ sid {
+        return high ^ 0x8000;
+    }
+    high
+}
+
+/// Draws the twelve calls of a Channel Jirachi from the rng, leaving it after the last one
+pub(crate) fn channel_jirachi<R: Rng + ?Sized>(rng: &mut R) -> ChannelJirachi {
+    let sid = rng.next_u16();
+    let high = rng.next_u16();
+    let low = rng.next_u16();
+    let pid = ((channel_pid_high(high, low, sid) as u32) << 16) | low as u32;
+
+    // berry, game of origin and OT gender
+    rng.advance(3);
+
+    ChannelJirachi {
+        pokemon: Pokemon::new(pid, channel_ivs(rng)),
+        sid,
+    }
+}
+
+fn channel_ivs<R: Rng + ?Sized>(rng: &mut R) -> IndividualValues {
+    let hp = (rng.next_u16() >> 11) as u8;
+    let atk = (rng.next_u16() >> 11) as u8;
+    let def = (rng.next_u16() >> 11) as u8;
+    let spe = (rng.next_u16() >> 11) as u8;
+    let spa = (rng.next_u16() >> 11) as u8;
+    let spd = (rng.next_u16() >> 11) as u8;
+
+    IndividualValues::new(hp, atk, def, spa, spd, spe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_generate_advances_one_frame() -> Result<(), String> {
+        let mut channel = Channel::new(0);
+        channel.generate();
+        assert_eq!(channel.rng.seed(), 0x00269EC3u32);
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_generated_jirachi_is_legal() -> Result<(), String> {
+        let mut channel = Channel::new(0xBEEFCAFEu32);
+
+        for _ in 0..5 {
+            let seed = channel.rng.seed();
+            let jirachi = channel.generate();
+            assert!(Channel::seeds_for(&jirachi.pokemon).contains(&seed));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_rejects_method_1_pokemon() -> Result<(), String> {
+        let mut lcrng = LinearCongruential::new(0);
+        let pokemon = crate::generators::Generator::method_1(&mut lcrng);
+        assert!(!Channel::is_legal(&pokemon));
+        Ok(())
+    }
+}
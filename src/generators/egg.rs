@@ -0,0 +1,113 @@
+use crate::generators::{LinearCongruential, MersenneTwister};
+use crate::pokemon::{IndividualValues, Pokemon};
+use crate::Profile;
+
+/// The most times a Gen 4 egg PID is rerolled when the parents come from different languages
+const MASUDA_METHOD_REROLLS: usize = 4;
+
+/// Generates the PID of a Gen 4 egg from the next number of the mersenne twister.
+///
+/// With the Masuda Method, where the parents come from games of different languages, a PID which is not shiny for the given profile
+/// is rerolled up to 4 times by advancing it through the ARNG, see `LinearCongruential::new_arng`.
+pub fn gen4_egg_pid(mt: &mut MersenneTwister, masuda_method: Option<&Profile>) -> u32 {
+    let mut arng = LinearCongruential::new_arng(mt.next_u32());
+
+    if let Some(profile) = masuda_method {
+        for _ in 0..MASUDA_METHOD_REROLLS {
+            let egg = Pokemon::new(arng.seed(), IndividualValues::default());
+            if egg.get_shininess(profile.tid, profile.sid) {
+                break;
+            }
+            arng.step();
+        }
+    }
+
+    arng.seed()
+}
+
+/// Generates the IVs of a Gen 4 egg when it is picked up from the Day Care, using the LCRNG.
+///
+/// Two RNG calls generate the IVs as in Method 1. Three calls then pick which stats are inherited, each choosing among the stats not yet picked
+/// in the order HP, Atk, Def, Spe, SpA, SpD, and a final three calls pick which parent each stat is inherited from.
+/// It can be illustrated as [IVs] [IVs] [stat] [stat] [stat] [parent] [parent] [parent].
+pub fn gen4_egg_ivs(
+    rng: &mut LinearCongruential,
+    parent_a: &IndividualValues,
+    parent_b: &IndividualValues,
+) -> IndividualValues {
+    let n1 = rng.next_u16();
+    let n2 = rng.next_u16();
+    let mut ivs = IndividualValues::new_from_numbers(n1, n2);
+
+    let mut available = vec![0, 1, 2, 3, 4, 5];
+    let mut inherited = Vec::new();
+    for _ in 0..3 {
+        let index = rng.next_u16() as usize % available.len();
+        inherited.push(available.remove(index));
+    }
+
+    for stat in inherited {
+        let parent = if rng.next_u16() & 1 == 0 {
+            parent_a
+        } else {
+            parent_b
+        };
+        match stat {
+            0 => ivs.hp = parent.hp,
+            1 => ivs.atk = parent.atk,
+            2 => ivs.def = parent.def,
+            3 => ivs.spe = parent.spe,
+            4 => ivs.spa = parent.spa,
+            _ => ivs.spd = parent.spd,
+        }
+    }
+
+    ivs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masuda_method_rerolls() -> Result<(), String> {
+        let first_pid = 3499211612u32;
+        let mut mt = MersenneTwister::new(5489);
+        assert_eq!(gen4_egg_pid(&mut mt, None), first_pid);
+
+        // a profile for which the first PID is already shiny keeps it
+        let shiny_tid = ((first_pid >> 16) ^ (first_pid & 0xFFFF)) as u16;
+        let mut mt = MersenneTwister::new(5489);
+        let pid = gen4_egg_pid(&mut mt, Some(&Profile::new(shiny_tid, 0)));
+        assert_eq!(pid, first_pid);
+
+        // otherwise every reroll is used up when none of them are shiny
+        let profile = Profile::new(12345, 54321);
+        let mut expected = first_pid;
+        for _ in 0..MASUDA_METHOD_REROLLS {
+            let egg = Pokemon::new(expected, IndividualValues::default());
+            assert!(!egg.get_shininess(profile.tid, profile.sid));
+            expected = expected.wrapping_mul(0x6C078965).wrapping_add(1);
+        }
+        let mut mt = MersenneTwister::new(5489);
+        assert_eq!(gen4_egg_pid(&mut mt, Some(&profile)), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_egg_ivs_inherit_three_stats() -> Result<(), String> {
+        let parent_a = IndividualValues::new(1, 2, 3, 4, 5, 6);
+        let parent_b = IndividualValues::new(11, 12, 13, 14, 15, 16);
+
+        // random IVs 17/27/2/7/1/10, then SpA, Spe and HP are inherited from parents B, A and B
+        let mut rng = LinearCongruential::new(0x12345678);
+        let ivs = gen4_egg_ivs(&mut rng, &parent_a, &parent_b);
+        assert!(ivs == IndividualValues::new(11, 27, 2, 14, 1, 6));
+
+        // 2 IV calls, 3 stat calls and 3 parent calls
+        let mut expected = LinearCongruential::new(0x12345678);
+        expected.jump(8);
+        assert_eq!(rng.seed(), expected.seed());
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
-use crate::pokemon::Pokemon;
+use crate::events::{PidOrder, SeedRange};
+use crate::generators::channel::channel_jirachi;
+use crate::generators::{
+    LinearCongruential, Pokewalker, Rng, SeedableRng, TanobyChamber, WildEncounter,
+};
+use crate::pokemon::{Gender, IndividualValues, Nature, Pokemon};
 
 pub trait Generator {
     /// Four RNG calls are made, two to generate the PID and two to generate the IVs. It can be illustrated as [PID] [PID] [IVs] [IVs].
@@ -7,14 +12,385 @@ pub trait Generator {
     /// Five RNG calls are made. The first two are used to generate the PID and the last two are used to generate the IVs. The third RNG call is not used for anything. It can be illustrated as [PID] [PID] [xxxx] [IVs] [IVs].
     fn method_2(&mut self) -> Pokemon;
 
+    /// Five RNG calls are made. The first and third are used to generate the PID and the last two are used to generate the IVs. The second RNG call is not used for anything. It can be illustrated as [PID] [xxxx] [PID] [IVs] [IVs].
+    fn method_3(&mut self) -> Pokemon;
+
+    /// Identical to Method 1, except that the first call becomes the high half of the PID rather than the low half, as used by some event distributions.
+    /// It can be illustrated as [PID high] [PID low] [IVs] [IVs].
+    fn method_1_reverse(&mut self) -> Pokemon;
+
     /// Five RNG calls are made. The first and second are used to generate the PID and the third and fifth are used to generate the IVs. The fourth RNG call is not used for anything. It can be illustrated as [PID] [PID] [IVs] [xxxx] [IVs].
     fn method_4(&mut self) -> Pokemon;
+
+    /// The wild method used for Unown in the Tanoby Chambers. The first three RNG calls pick the encounter slot, level and nature, and the slot decides the Unown form.
+    /// PIDs are then generated with the high half first, and rerolled until both the nature and the form match. The IVs follow as in Method 1.
+    /// It can be illustrated as [slot] [level] [nature] [PID] [PID] ... [PID] [PID] [IVs] [IVs].
+    fn method_h1_tanoby(&mut self, chamber: TanobyChamber) -> Pokemon;
+
+    /// The wild method used by Diamond, Pearl and Platinum. The encounter rolls its slot, and its bite and level where it has them, see `WildEncounter`,
+    /// then the nature is chosen by dividing a random number by 0xA3E. PIDs are rerolled until the nature matches, and the IVs follow as in Method 1.
+    /// It can be illustrated as [slot] [nature] [PID] [PID] ... [PID] [PID] [IVs] [IVs] for grass.
+    /// Returns None when nothing bites a fishing rod.
+    fn method_j(&mut self, lead: Lead, encounter: WildEncounter) -> Option<Pokemon>;
+
+    /// The wild method used by HeartGold and SoulSilver. It is identical to Method J except that the nature, slot, bite and level are chosen by taking random numbers modulo their range,
+    /// and the lead ability checks use the low bits of their random number rather than the high bits.
+    fn method_k(&mut self, lead: Lead, encounter: WildEncounter) -> Option<Pokemon>;
+
+    /// Pokemon from the Pokéwalker have a PID computed from the trainer and the rolled nature and gender, so only two RNG calls are made, both for the IVs.
+    /// It can be illustrated as [IVs] [IVs].
+    fn pokewalker(&mut self, pokewalker: Pokewalker) -> Pokemon;
+}
+
+/// Every rng whose state is its seed restores it after each frame, so that it moves on by a single call.
+/// Other rngs generate pokemon through `Searcher::with_replay`, which replays their outputs rather than copying their state.
+impl<R: SeedableRng> Generator for R {
+    fn method_1(&mut self) -> Pokemon {
+        single_frame(self, method_1)
+    }
+
+    fn method_2(&mut self) -> Pokemon {
+        single_frame(self, method_2)
+    }
+
+    fn method_3(&mut self) -> Pokemon {
+        single_frame(self, method_3)
+    }
+
+    fn method_1_reverse(&mut self) -> Pokemon {
+        single_frame(self, method_1_reverse)
+    }
+
+    fn method_4(&mut self) -> Pokemon {
+        single_frame(self, method_4)
+    }
+
+    fn method_h1_tanoby(&mut self, chamber: TanobyChamber) -> Pokemon {
+        single_frame(self, |rng| method_h1_tanoby(rng, chamber).0)
+    }
+
+    fn method_j(&mut self, lead: Lead, encounter: WildEncounter) -> Option<Pokemon> {
+        single_frame(self, |rng| {
+            method_gen4_wild(rng, lead, encounter, false).map(|wild| wild.0)
+        })
+    }
+
+    fn method_k(&mut self, lead: Lead, encounter: WildEncounter) -> Option<Pokemon> {
+        single_frame(self, |rng| {
+            method_gen4_wild(rng, lead, encounter, true).map(|wild| wild.0)
+        })
+    }
+
+    fn pokewalker(&mut self, pokewalker: Pokewalker) -> Pokemon {
+        single_frame(self, |rng| pokewalker_frame(rng, pokewalker))
+    }
+}
+
+/// Generates the pokemon of the current frame, then leaves the rng a single call further
+fn single_frame<R: SeedableRng, T, F: FnOnce(&mut R) -> T>(rng: &mut R, generate: F) -> T {
+    let seed = rng.seed();
+    let generated = generate(rng);
+    rng.reseed(seed);
+    rng.next_u32();
+    generated
+}
+
+/// Counts the calls made to the rng it wraps
+struct Counted<'a, R: Rng + ?Sized> {
+    rng: &'a mut R,
+    calls: usize,
+}
+
+impl<R: Rng + ?Sized> Rng for Counted<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.calls += 1;
+        self.rng.next_u32()
+    }
+
+    fn advance(&mut self, advances: usize) {
+        self.calls += advances;
+        self.rng.advance(advances);
+    }
+}
+
+/// Draws a PID from two calls, the first becoming the low half, as `LinearCongruential::generate_pid` does
+fn generate_pid<R: Rng + ?Sized>(rng: &mut R) -> u32 {
+    let n1 = rng.next_u16() as u32;
+    let n2 = rng.next_u16() as u32;
+    (n2 << 16) | n1
+}
+
+/// Draws the IVs from two consecutive calls
+fn generate_ivs<R: Rng + ?Sized>(rng: &mut R) -> IndividualValues {
+    let n1 = rng.next_u16();
+    let n2 = rng.next_u16();
+    IndividualValues::new_from_numbers(n1, n2)
+}
+
+fn method_1<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let pid = generate_pid(rng);
+    Pokemon::new(pid, generate_ivs(rng))
+}
+
+fn method_2<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let pid = generate_pid(rng);
+    rng.next_u32();
+    Pokemon::new(pid, generate_ivs(rng))
+}
+
+fn method_3<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let pid_low = rng.next_u16() as u32;
+    rng.next_u32();
+    let pid_high = rng.next_u16() as u32;
+    Pokemon::new((pid_high << 16) | pid_low, generate_ivs(rng))
+}
+
+fn method_1_reverse<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let pid = generate_pid(rng).rotate_left(16);
+    Pokemon::new(pid, generate_ivs(rng))
+}
+
+fn method_4<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let pid = generate_pid(rng);
+    let n1 = rng.next_u16();
+    rng.next_u32();
+    let n2 = rng.next_u16();
+    Pokemon::new(pid, IndividualValues::new_from_numbers(n1, n2))
+}
+
+fn xd_colo<R: Rng + ?Sized>(rng: &mut R) -> Pokemon {
+    let ivs = generate_ivs(rng);
+    // ability
+    rng.next_u32();
+    let high = rng.next_u16() as u32;
+    let low = rng.next_u16() as u32;
+    Pokemon::new((high << 16) | low, ivs)
+}
+
+fn pokewalker_frame<R: Rng + ?Sized>(rng: &mut R, pokewalker: Pokewalker) -> Pokemon {
+    Pokemon::new(pokewalker.pid(), generate_ivs(rng))
+}
+
+/// The wild method used for Unown in the Tanoby Chambers, returning the pokemon along with its encounter slot
+fn method_h1_tanoby<R: Rng + ?Sized>(rng: &mut R, chamber: TanobyChamber) -> (Pokemon, u8) {
+    let slot = chamber.slot_for(rng.next_u16());
+    let letter = chamber.slot_letters()[slot as usize];
+    rng.next_u32();
+    let nature = (rng.next_u16() % 25) as u32;
+
+    let pid = loop {
+        let high = rng.next_u16() as u32;
+        let low = rng.next_u16() as u32;
+        let pid = (high << 16) | low;
+        let candidate = Pokemon::new(pid, IndividualValues::default());
+        if pid % 25 == nature && candidate.get_unown_form() == letter {
+            break pid;
+        }
+    };
+
+    (Pokemon::new(pid, generate_ivs(rng)), slot)
+}
+
+/// Shared body of Methods J and K, which only differ in how random numbers are turned into the encounter, nature and lead ability checks.
+/// Returns the pokemon along with its encounter slot and level, or None when nothing bites.
+fn method_gen4_wild<R: Rng + ?Sized>(
+    rng: &mut R,
+    lead: Lead,
+    encounter: WildEncounter,
+    method_k: bool,
+) -> Option<(Pokemon, u8, Option<u8>)> {
+    let (slot, level) = encounter.roll(rng, method_k)?;
+
+    let to_nature = |rand: u16| {
+        if method_k {
+            (rand % 25) as u32
+        } else {
+            (rand / 0xA3E) as u32
+        }
+    };
+
+    let mut cute_charm_buffer = None;
+    let nature = match lead {
+        Lead::None => to_nature(rng.next_u16()),
+        Lead::Synchronize(nature) => {
+            let rand = rng.next_u16();
+            let synchronized = if method_k {
+                rand & 1 == 0
+            } else {
+                rand >> 15 == 0
+            };
+            if synchronized {
+                nature as u32
+            } else {
+                to_nature(rng.next_u16())
+            }
+        }
+        Lead::CuteCharm {
+            lead,
+            gender_threshold,
+        } => {
+            let rand = rng.next_u16();
+            let charmed = if method_k {
+                !rand.is_multiple_of(3)
+            } else {
+                rand / 0x5556 != 0
+            };
+            if charmed {
+                cute_charm_buffer = Some(match lead {
+                    Gender::Female => 25 * (gender_threshold as u32 / 25 + 1),
+                    Gender::Male => 0,
+                });
+            }
+            to_nature(rng.next_u16())
+        }
+    };
+
+    let pid = match cute_charm_buffer {
+        Some(buffer) => buffer + nature,
+        None => loop {
+            let pid = generate_pid(rng);
+            if pid % 25 == nature {
+                break pid;
+            }
+        },
+    };
+
+    Some((Pokemon::new(pid, generate_ivs(rng)), slot, level))
 }
 
+/// The ability of the pokemon leading the party, which can influence Gen 4 wild encounters
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Lead {
+    None,
+    /// Half of the time, the wild pokemon takes the lead's nature and no nature call is made
+    Synchronize(Nature),
+    /// Two thirds of the time, the wild pokemon is given the opposite gender of the lead, by skipping the PID calls and building the PID from the nature.
+    /// The gender threshold is that of the wild species, the gender value below which a pokemon is female, eg 127 for a species which is half female.
+    CuteCharm {
+        lead: Gender,
+        gender_threshold: u8,
+    },
+}
+
+/// A pokemon generated by a `GenerationMethod`, along with what the method knows about how it was generated
+#[derive(Debug)]
+pub struct Generated {
+    pub pokemon: Pokemon,
+    /// The number of RNG calls made to generate the pokemon, including any rerolls
+    pub advances: usize,
+    /// The encounter slot, for methods which roll one
+    pub slot: Option<u8>,
+    /// The level, for methods which roll one
+    pub level: Option<u8>,
+}
+
+impl Generated {
+    pub fn new(pokemon: Pokemon, advances: usize) -> Self {
+        Generated {
+            pokemon,
+            advances,
+            slot: None,
+            level: None,
+        }
+    }
+
+    pub fn with_slot(mut self, slot: u8) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    pub fn with_level(mut self, level: u8) -> Self {
+        self.level = Some(level);
+        self
+    }
+}
+
+/// A way of generating a pokemon from the RNG, which `Searcher` runs once per frame.
+///
+/// Implementations may advance the RNG as many times as they need. The searcher restores the seed afterwards and steps to the next frame,
+/// so methods written outside of masuda only need to describe the RNG calls of a single frame.
+pub trait GenerationMethod {
+    /// Generates the pokemon of a frame, or None when the frame produces no encounter, such as when nothing bites a fishing rod
+    fn generate(&self, rng: &mut dyn Rng) -> Option<Generated>;
+}
+
+#[derive(Copy, Clone)]
 pub enum Method {
     One,
     Two,
+    Three,
     Four,
+    /// Method 1 with the halves of the PID drawn in the reverse order
+    OneReverse,
+    /// Method 1 as used by Gen 3 event distributions, where the order of the PID halves varies by event.
+    /// Restricted distributions can only start from 16-bit seeds, which seed searches take into account, while frame searches generate as usual.
+    Event(PidOrder, SeedRange),
+    TanobyH1(TanobyChamber),
+    J(Lead, WildEncounter),
+    K(Lead, WildEncounter),
+    Pokewalker(Pokewalker),
+    /// The shadow and gift pokemon of Colosseum and XD, drawn from the XDRNG, see `Method::new_rng`.
+    /// It can be illustrated as [IVs] [IVs] [ability] [PID high] [PID low].
+    XdColo,
+    /// The Jirachi of Pokemon Channel, drawn from the XDRNG, see `Channel`
+    Channel,
+}
+
+impl Method {
+    /// Generates the pokemon of the current frame along with its encounter slot and level, leaving the rng after the last call made.
+    /// Returns None when the frame produces no encounter.
+    fn generate_pokemon<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Option<(Pokemon, Option<u8>, Option<u8>)> {
+        let (pokemon, slot) = match *self {
+            Method::One | Method::Event(PidOrder::Standard, _) => (method_1(rng), None),
+            Method::Two => (method_2(rng), None),
+            Method::Three => (method_3(rng), None),
+            Method::Four => (method_4(rng), None),
+            Method::OneReverse | Method::Event(PidOrder::Reverse, _) => {
+                (method_1_reverse(rng), None)
+            }
+            Method::TanobyH1(chamber) => {
+                let (pokemon, slot) = method_h1_tanoby(rng, chamber);
+                (pokemon, Some(slot))
+            }
+            Method::J(lead, encounter) | Method::K(lead, encounter) => {
+                let method_k = matches!(self, Method::K(..));
+                let (pokemon, slot, level) = method_gen4_wild(rng, lead, encounter, method_k)?;
+                return Some((pokemon, Some(slot), level));
+            }
+            Method::Pokewalker(pokewalker) => (pokewalker_frame(rng, pokewalker), None),
+            Method::XdColo => (xd_colo(rng), None),
+            Method::Channel => (channel_jirachi(rng).pokemon, None),
+        };
+        Some((pokemon, slot, None))
+    }
+
+    /// Constructs the rng the method draws from, the XDRNG for the GameCube methods and the LCRNG otherwise
+    pub fn new_rng(&self, seed: u32) -> LinearCongruential {
+        match self {
+            Method::XdColo | Method::Channel => LinearCongruential::new_xd(seed),
+            _ => LinearCongruential::new(seed),
+        }
+    }
+
+    /// Generates the pokemon of the current frame, counting the calls made to the rng
+    fn generate_from<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Generated> {
+        let mut counted = Counted { rng, calls: 0 };
+        let (pokemon, slot, level) = self.generate_pokemon(&mut counted)?;
+
+        let mut generated = Generated::new(pokemon, counted.calls);
+        generated.slot = slot;
+        generated.level = level;
+        Some(generated)
+    }
+}
+
+impl GenerationMethod for Method {
+    fn generate(&self, rng: &mut dyn Rng) -> Option<Generated> {
+        self.generate_from(rng)
+    }
 }
 
 pub enum Game {
@@ -23,4 +399,9 @@ pub enum Game {
     Emerald,
     Ruby,
     Sapphire,
+    Diamond,
+    Pearl,
+    Platinum,
+    HeartGold,
+    SoulSilver,
 }
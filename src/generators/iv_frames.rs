@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+use crate::generators::MersenneTwister;
+use crate::pokemon::IndividualValues;
+
+/// The IVs produced at a frame of the Gen 5 mersenne twister
+pub struct IvFrame {
+    pub frame: usize,
+    pub ivs: IndividualValues,
+}
+
+/// Generates the IVs of Black, White, Black 2 and White 2 for each frame in the range.
+///
+/// Gen 5 draws IVs from the mersenne twister seeded with the top 32 bits of the initial seed, separately from the PID rng.
+/// Six consecutive numbers are used, one per stat in the order HP, Atk, Def, SpA, SpD, Spe, and each IV is the top 5 bits of its number.
+/// Frames count from 1, with frame 1 starting at the first number of the twister.
+pub fn gen5_iv_frames(seed: u64, frames: RangeInclusive<usize>) -> Vec<IvFrame> {
+    let mut mt = MersenneTwister::new((seed >> 32) as u32);
+    let first_frame = *frames.start().max(&1);
+    for _ in 1..first_frame {
+        mt.step();
+    }
+
+    let mut window: VecDeque<u8> = (0..6).map(|_| (mt.next_u32() >> 27) as u8).collect();
+    let mut iv_frames = Vec::new();
+    for frame in first_frame..=*frames.end() {
+        if frame > first_frame {
+            window.pop_front();
+            window.push_back((mt.next_u32() >> 27) as u8);
+        }
+        iv_frames.push(IvFrame {
+            frame,
+            ivs: IndividualValues::new(
+                window[0], window[1], window[2], window[3], window[4], window[5],
+            ),
+        });
+    }
+
+    iv_frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iv_frames_slide_by_one() -> Result<(), String> {
+        // the top 32 bits of the seed are the reference seed of MT19937
+        let seed = 5489u64 << 32;
+        let frames = gen5_iv_frames(seed, 1..=2);
+
+        // 3499211612, 581869302, 3890346734, 3586334585, 545404204, 4161255391, 3922919429 shifted right by 27
+        assert_eq!(frames[0].frame, 1);
+        assert_eq!(frames[0].ivs, IndividualValues::new(26, 4, 28, 26, 4, 31));
+        assert_eq!(frames[1].ivs, IndividualValues::new(4, 28, 26, 4, 31, 29));
+
+        let later = gen5_iv_frames(seed, 2..=2);
+        assert_eq!(later[0].ivs, frames[1].ivs);
+        Ok(())
+    }
+}
@@ -1,36 +1,82 @@
 use std::convert::TryFrom;
 
-use crate::generators::Generator;
-use crate::pokemon::{IndividualValues, Pokemon};
+use crate::generators::{Rng, SeedableRng};
 
 /// Represents a linear congruential generator https://en.wikipedia.org/wiki/Linear_congruential_generator
+#[derive(Clone)]
 pub struct LinearCongruential {
     seed: u32,
     multiplier: u32,
     increment: u32,
+    reverse_multiplier: u32,
+    reverse_increment: u32,
 }
 
 impl LinearCongruential {
     /// Constructs a new lcrng instance with the given initial seed
     pub fn new(initial_seed: u32) -> Self {
+        Self::with_constants(initial_seed, 0x41C64E6Du32, 0x6073u32)
+    }
+
+    /// Constructs the lcrng used by the GameCube games (Colosseum, XD and Pokemon Channel), commonly called the XDRNG
+    pub fn new_xd(initial_seed: u32) -> Self {
+        Self::with_constants(initial_seed, 0x343FDu32, 0x269EC3u32)
+    }
+
+    /// Constructs the alternate lcrng of Gen 4, commonly called the ARNG, used for things like Pokérus and the Masuda Method rerolls
+    pub fn new_arng(initial_seed: u32) -> Self {
+        Self::with_constants(initial_seed, 0x6C078965u32, 0x1u32)
+    }
+
+    /// Constructs the secondary lcrng of Gen 3, which shares the multiplier of the main lcrng but increments by 0x3039.
+    /// Emerald uses it for the values updated once a day, such as the Lottery Corner number and the Feebas tiles.
+    pub fn new_gen3_secondary(initial_seed: u32) -> Self {
+        Self::with_constants(initial_seed, 0x41C64E6Du32, 0x3039u32)
+    }
+
+    /// Constructs an lcrng with an arbitrary multiplier and increment.
+    ///
+    /// The multiplier must be odd so that the generator can also be stepped backwards.
+    fn with_constants(initial_seed: u32, multiplier: u32, increment: u32) -> Self {
+        // newton's method doubles the number of correct low bits on every iteration
+        let mut reverse_multiplier = multiplier;
+        for _ in 0..5 {
+            reverse_multiplier = reverse_multiplier
+                .wrapping_mul(2u32.wrapping_sub(multiplier.wrapping_mul(reverse_multiplier)));
+        }
+        let reverse_increment = increment.wrapping_mul(reverse_multiplier).wrapping_neg();
+
         LinearCongruential {
             seed: initial_seed,
-            multiplier: 0x41C64E6Du32,
-            increment: 0x6073u32,
+            multiplier,
+            increment,
+            reverse_multiplier,
+            reverse_increment,
         }
     }
 
+    /// Getter for seed attribute
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
     /// Setter for seed attribute
     pub fn set_seed(&mut self, seed: u32) {
         self.seed = seed
     }
 
+    /// The multiplier and increment of a single step
+    pub(crate) fn constants(&self) -> (u32, u32) {
+        (self.multiplier, self.increment)
+    }
+
     /// Step advances the rng by calling next_u32 and discarding result
     pub fn step(&mut self) {
         self.next_u32();
     }
 
     /// Advances the rng, outputting a new u32 and setting the seed of the instance
+    #[inline]
     pub fn next_u32(&mut self) -> u32 {
         let result = (self.seed as u64 * self.multiplier as u64) + self.increment as u64;
         let bitmapped_result = result & 0xFFFFFFFF; // last 32 bits
@@ -39,6 +85,21 @@ impl LinearCongruential {
         result_u32
     }
 
+    /// Jumps the rng ahead by the given number of advances in logarithmic time, equivalent to calling step that many times
+    pub fn jump(&mut self, advances: u64) {
+        let (multiplier, increment) = jump_constants(self.multiplier, self.increment, advances);
+        self.seed = self.seed.wrapping_mul(multiplier).wrapping_add(increment);
+    }
+
+    /// Steps the rng backwards, undoing the most recent call to next_u32 and returning the restored seed
+    pub fn prev_u32(&mut self) -> u32 {
+        self.seed = self
+            .seed
+            .wrapping_mul(self.reverse_multiplier)
+            .wrapping_add(self.reverse_increment);
+        self.seed
+    }
+
     /// Advances the rng, outputting the the first 16 bits of the newly generated seed
     pub fn next_u16(&mut self) -> u16 {
         let new_u32 = self.next_u32();
@@ -60,59 +121,59 @@ impl LinearCongruential {
     }
 }
 
-impl Generator for LinearCongruential {
-    /// Four RNG calls are made, two to generate the PID and two to generate the IVs. It can be illustrated as [PID] [PID] [IVs] [IVs].
-    fn method_1(&mut self) -> Pokemon {
-        let og_seed = self.seed;
-
-        let pid = self.generate_pid();
-        let n1 = self.next_u16();
-        let n2 = self.next_u16();
-        let ivs = IndividualValues::new_from_numbers(n1, n2);
-
-        self.set_seed(og_seed);
-        self.step();
-
-        Pokemon::new(pid, ivs)
+impl Rng for LinearCongruential {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        LinearCongruential::next_u32(self)
     }
 
-    /// Five RNG calls are made. The first two are used to generate the PID and the last two are used to generate the IVs. The third RNG call is not used for anything. It can be illustrated as [PID] [PID] [xxxx] [IVs] [IVs].
-    fn method_2(&mut self) -> Pokemon {
-        let og_seed = self.seed;
-
-        let pid = self.generate_pid();
-        self.next_u16();
-        let n1 = self.next_u16();
-        let n2 = self.next_u16();
-        let ivs = IndividualValues::new_from_numbers(n1, n2);
-
-        self.set_seed(og_seed);
-        self.step();
-
-        Pokemon::new(pid, ivs)
+    #[inline]
+    fn advance(&mut self, advances: usize) {
+        self.jump(advances as u64);
     }
+}
 
-    /// Five RNG calls are made. The first and second are used to generate the PID and the third and fifth are used to generate the IVs. The fourth RNG call is not used for anything. It can be illustrated as [PID] [PID] [IVs] [xxxx] [IVs].
-    fn method_4(&mut self) -> Pokemon {
-        let og_seed = self.seed;
+impl SeedableRng for LinearCongruential {
+    type Seed = u32;
 
-        let pid = self.generate_pid();
-        let n1 = self.next_u16();
-        self.next_u16();
-        let n2 = self.next_u16();
-        let ivs = IndividualValues::new_from_numbers(n1, n2);
+    #[inline]
+    fn seed(&self) -> u32 {
+        self.seed
+    }
 
-        self.set_seed(og_seed);
-        self.step();
+    #[inline]
+    fn reseed(&mut self, seed: u32) {
+        self.seed = seed;
+    }
+}
 
-        Pokemon::new(pid, ivs)
+/// Computes the multiplier and increment which advance an lcrng by the given number of steps at once,
+/// by repeatedly squaring the single step transformation
+pub(crate) fn jump_constants(multiplier: u32, increment: u32, advances: u64) -> (u32, u32) {
+    let (mut jump_multiplier, mut jump_increment) = (1u32, 0u32);
+    let (mut step_multiplier, mut step_increment) = (multiplier, increment);
+    let mut remaining = advances;
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            jump_multiplier = jump_multiplier.wrapping_mul(step_multiplier);
+            jump_increment = jump_increment
+                .wrapping_mul(step_multiplier)
+                .wrapping_add(step_increment);
+        }
+        step_increment = step_increment.wrapping_mul(step_multiplier.wrapping_add(1));
+        step_multiplier = step_multiplier.wrapping_mul(step_multiplier);
+        remaining >>= 1;
     }
+
+    (jump_multiplier, jump_increment)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pokemon::{Gender, Nature};
+    use crate::generators::{Generator, Lead, TanobyChamber, WildEncounter};
+    use crate::pokemon::{Gender, IndividualValues, Nature};
 
     #[test]
     fn test_lcrng_new() -> Result<(), String> {
@@ -152,6 +213,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_lcrng_prev_32() -> Result<(), String> {
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        lcrng.next_u32();
+        lcrng.next_u32();
+        assert_eq!(lcrng.prev_u32(), 0x01DBBC30u32);
+        assert_eq!(lcrng.prev_u32(), 0x1A56B091u32);
+        Ok(())
+    }
+
+    #[test]
+    fn test_xdrng_round_trip() -> Result<(), String> {
+        let mut xdrng = LinearCongruential::new_xd(0);
+        assert_eq!(xdrng.next_u32(), 0x00269EC3u32);
+        assert_eq!(xdrng.next_u32(), 0x1E278E7Au32);
+        xdrng.prev_u32();
+        assert_eq!(xdrng.prev_u32(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_generate_pid() -> Result<(), String> {
         let mut lcrng = LinearCongruential::new(0x1A56B091u32);
@@ -177,6 +258,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_generate_tanoby_matches_chamber() -> Result<(), String> {
+        let mut lcrng = LinearCongruential::new(0);
+
+        for _ in 0..100 {
+            let slot_rand = LinearCongruential::new(lcrng.seed).next_u16();
+            let letter = TanobyChamber::Liptoo.letter_for(slot_rand);
+            let generated_pokemon = lcrng.method_h1_tanoby(TanobyChamber::Liptoo);
+            assert_eq!(generated_pokemon.get_unown_form(), letter);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_method_j_and_k_natures() -> Result<(), String> {
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        // the nature call is the second call, 0x7B06
+        let pokemon = lcrng
+            .method_j(Lead::None, WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(pokemon.get_nature() as u16, 0x7B06 / 0xA3E);
+
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        let pokemon = lcrng
+            .method_k(Lead::None, WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(pokemon.get_nature() as u16, 0x7B06 % 25);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_method_j_leads() -> Result<(), String> {
+        // 0x7B06 >> 15 is 0, so synchronize takes effect
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        let synchronized = lcrng
+            .method_j(Lead::Synchronize(Nature::Timid), WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(synchronized.get_nature(), Nature::Timid);
+
+        // 0x7B06 / 0x5556 is 1, so cute charm takes effect and the PID is built from the nature call 0x5233
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        let charmed = lcrng
+            .method_j(
+                Lead::CuteCharm {
+                    lead: Gender::Female,
+                    gender_threshold: 127,
+                },
+                WildEncounter::Grass,
+            )
+            .ok_or("no encounter")?;
+        assert_eq!(charmed.pid, 150 + 0x5233 / 0xA3E);
+        assert_eq!(charmed.get_gender_50_f(), Gender::Male);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_method_k_leads() -> Result<(), String> {
+        // 0x7B06 & 1 is 0, so synchronize takes effect and PIDs are rerolled until they are Adamant
+        let mut lcrng = LinearCongruential::new(0x1A56B091u32);
+        let synchronized = lcrng
+            .method_k(Lead::Synchronize(Nature::Adamant), WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(synchronized.pid, 0x335DC539);
+        assert_eq!(synchronized.get_nature(), Nature::Adamant);
+
+        // 0xB4DB & 1 is 1, so the nature is rolled by the next call instead
+        let mut lcrng = LinearCongruential::new(0xDEADBEEFu32);
+        let unsynchronized = lcrng
+            .method_k(Lead::Synchronize(Nature::Adamant), WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(unsynchronized.pid, 0x3396A5CA);
+
+        // 0x1877 % 3 is 1, so cute charm takes effect and the PID is built from the nature call 0x070E
+        let cute_charm = Lead::CuteCharm {
+            lead: Gender::Female,
+            gender_threshold: 127,
+        };
+        let mut lcrng = LinearCongruential::new(0x0BADF00Du32);
+        let charmed = lcrng
+            .method_k(cute_charm, WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(charmed.pid, 0x9C);
+
+        // 0xB4DB % 3 is 0, so the PID is rerolled as without a lead
+        let mut lcrng = LinearCongruential::new(0xDEADBEEFu32);
+        let uncharmed = lcrng
+            .method_k(cute_charm, WildEncounter::Grass)
+            .ok_or("no encounter")?;
+        assert_eq!(uncharmed.pid, 0x3396A5CA);
+        Ok(())
+    }
+
     #[test]
     fn test_generate_method_1_frame_sequence() -> Result<(), String> {
         let mut lcrng = LinearCongruential::new(0);
@@ -195,4 +369,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_jump() -> Result<(), String> {
+        let mut stepped = LinearCongruential::new(0x12345678);
+        for _ in 0..12345 {
+            stepped.step();
+        }
+        let mut jumped = LinearCongruential::new(0x12345678);
+        jumped.jump(12345);
+        assert_eq!(jumped.seed(), stepped.seed());
+
+        // the lcrng has a period of 2^32
+        jumped.jump(1 << 32);
+        assert_eq!(jumped.seed(), stepped.seed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_alternate_lcrngs() -> Result<(), String> {
+        let mut arng = LinearCongruential::new_arng(0);
+        assert_eq!(arng.next_u32(), 0x00000001);
+        assert_eq!(arng.next_u32(), 0x6C078966);
+        assert_eq!(arng.next_u32(), 0x714ACB3F);
+        assert_eq!(arng.prev_u32(), 0x6C078966);
+
+        let mut secondary = LinearCongruential::new_gen3_secondary(0);
+        assert_eq!(secondary.next_u32(), 0x00003039);
+        assert_eq!(secondary.next_u32(), 0xD3DC167E);
+        assert_eq!(secondary.prev_u32(), 0x00003039);
+        Ok(())
+    }
 }
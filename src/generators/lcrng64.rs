@@ -0,0 +1,84 @@
+use crate::generators::{Rng, SeedableRng};
+
+/// Represents the 64-bit linear congruential generator used by Black, White, Black 2 and White 2 for PIDs, often called the BWRNG
+#[derive(Clone)]
+pub struct LinearCongruential64 {
+    seed: u64,
+    multiplier: u64,
+    increment: u64,
+}
+
+impl LinearCongruential64 {
+    /// Constructs a new 64-bit lcrng instance with the given initial seed
+    pub fn new(initial_seed: u64) -> Self {
+        LinearCongruential64 {
+            seed: initial_seed,
+            multiplier: 0x5D588B656C078965u64,
+            increment: 0x269EC3u64,
+        }
+    }
+
+    /// Getter for seed attribute
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Step advances the rng by calling next_u64 and discarding result
+    pub fn step(&mut self) {
+        self.next_u64();
+    }
+
+    /// Advances the rng, outputting a new u64 and setting the seed of the instance
+    pub fn next_u64(&mut self) -> u64 {
+        self.seed = self
+            .seed
+            .wrapping_mul(self.multiplier)
+            .wrapping_add(self.increment);
+        self.seed
+    }
+
+    /// Advances the rng, outputting the first 32 bits of the newly generated seed
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Advances the rng, outputting a number between 0 and max exclusive.
+    /// Gen 5 scales the first 32 bits of the seed by the range rather than taking a modulo.
+    pub fn next_u32_max(&mut self, max: u32) -> u32 {
+        ((self.next_u32() as u64 * max as u64) >> 32) as u32
+    }
+}
+
+impl Rng for LinearCongruential64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        LinearCongruential64::next_u32(self)
+    }
+}
+
+impl SeedableRng for LinearCongruential64 {
+    type Seed = u64;
+
+    #[inline]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    #[inline]
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcrng64_next() -> Result<(), String> {
+        let mut lcrng = LinearCongruential64::new(0);
+        assert_eq!(lcrng.next_u64(), 0x269EC3u64);
+        assert_eq!(lcrng.next_u32(), 0x7188D00Cu32);
+        Ok(())
+    }
+}
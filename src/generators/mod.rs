@@ -1,5 +1,31 @@
+mod advancement;
+mod batch;
+mod channel;
+mod egg;
 mod generator;
+mod iv_frames;
 mod lcrng;
+mod lcrng64;
+mod mersenne_twister;
+mod pokerus;
+mod pokewalker;
+mod rng;
+mod sfmt;
+mod tanoby;
+mod wild;
 
-pub use self::generator::{Game, Generator, Method};
+pub use self::advancement::{EncounterTrigger, FrameAdvancement};
+pub use self::batch::{LcrngBatch, BATCH_LANES};
+pub use self::channel::{Channel, ChannelJirachi, CHANNEL_TID};
+pub use self::egg::{gen4_egg_ivs, gen4_egg_pid};
+pub use self::generator::{Game, Generated, GenerationMethod, Generator, Lead, Method};
+pub use self::iv_frames::{gen5_iv_frames, IvFrame};
 pub use self::lcrng::LinearCongruential;
+pub use self::lcrng64::LinearCongruential64;
+pub use self::mersenne_twister::MersenneTwister;
+pub use self::pokerus::{pokerus_frames, pokerus_infects};
+pub use self::pokewalker::Pokewalker;
+pub use self::rng::{Rng, SeedableRng};
+pub use self::sfmt::Sfmt;
+pub use self::tanoby::TanobyChamber;
+pub use self::wild::{Rod, SlotLevels, WildEncounter};
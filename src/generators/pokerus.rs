@@ -0,0 +1,40 @@
+use crate::generators::Rng;
+
+/// Random numbers which infect the party with Pokérus, a chance of 3 in 65536
+const POKERUS_RANDS: [u16; 3] = [0x4000, 0x8000, 0xC000];
+
+/// Checks whether the next number of the rng infects the party with Pokérus after a battle.
+///
+/// Gen 4 draws the number from the ARNG, see `LinearCongruential::new_arng`, while Gen 3 draws it from the main lcrng.
+pub fn pokerus_infects<R: Rng>(rng: &mut R) -> bool {
+    POKERUS_RANDS.contains(&rng.next_u16())
+}
+
+/// Finds the frames, up to the given limit, on which a battle ending infects the party with Pokérus.
+/// Each frame is a single advance of the rng, and the check only draws a single number, so each frame checks the next output of the rng.
+pub fn pokerus_frames<R: Rng>(rng: &mut R, frame_limit: usize) -> Vec<usize> {
+    (0..frame_limit).filter(|_| pokerus_infects(rng)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::LinearCongruential;
+
+    #[test]
+    fn test_pokerus() -> Result<(), String> {
+        // an ARNG seed whose next output has 0x8000 as its high half
+        let mut arng = LinearCongruential::new_arng(0);
+        arng.set_seed(0x8000_0000);
+        arng.prev_u32();
+        assert!(pokerus_infects(&mut arng.clone()));
+
+        let mut frames_before = LinearCongruential::new_arng(0);
+        frames_before.set_seed(arng.seed());
+        for _ in 0..5 {
+            frames_before.prev_u32();
+        }
+        assert_eq!(pokerus_frames(&mut frames_before, 6), vec![5]);
+        Ok(())
+    }
+}
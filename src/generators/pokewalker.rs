@@ -0,0 +1,70 @@
+use crate::pokemon::{Gender, Nature};
+
+/// A pokemon brought back from the Pokéwalker in HeartGold and SoulSilver.
+///
+/// Its PID is not random at all. It is computed from the trainer's ids and the nature and gender rolled by the Pokéwalker,
+/// and the top byte is chosen so that the pokemon can never be shiny.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pokewalker {
+    pub tid: u16,
+    pub sid: u16,
+    pub nature: Nature,
+    /// The gender of the pokemon along with the gender ratio of its species, the gender value below which a pokemon is female, eg 127 for half female.
+    /// Genderless species and species of a single gender have no gender adjustment.
+    pub gender: Option<(Gender, u8)>,
+}
+
+impl Pokewalker {
+    pub fn new(tid: u16, sid: u16, nature: Nature, gender: Option<(Gender, u8)>) -> Self {
+        Pokewalker {
+            tid,
+            sid,
+            nature,
+            gender,
+        }
+    }
+
+    /// Computes the PID, starting from a top byte which is the inverse of the high byte of TID XOR SID.
+    /// The PID is moved to the requested nature, then moved in steps of 25 until its low byte gives the requested gender.
+    pub fn pid(&self) -> u32 {
+        let mut pid = ((((self.tid ^ self.sid) >> 8) ^ 0xFF) as u32) << 24;
+        pid = pid.wrapping_add(self.nature as u32).wrapping_sub(pid % 25);
+
+        if let Some((gender, ratio)) = self.gender {
+            let ratio = ratio as u32;
+            let gender_value = pid & 0xFF;
+            match gender {
+                Gender::Male if gender_value < ratio => {
+                    pid += ((ratio - gender_value) / 25 + 1) * 25;
+                }
+                Gender::Female if gender_value >= ratio => {
+                    pid -= ((gender_value - ratio) / 25 + 1) * 25;
+                }
+                _ => {}
+            }
+        }
+
+        pid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::{IndividualValues, Pokemon};
+
+    #[test]
+    fn test_pokewalker_pid() -> Result<(), String> {
+        for nature in [Nature::Hardy, Nature::Adamant, Nature::Quirky].iter() {
+            for gender in [Gender::Male, Gender::Female].iter() {
+                let walker = Pokewalker::new(10101, 12345, *nature, Some((*gender, 127)));
+                let pokemon = Pokemon::new(walker.pid(), IndividualValues::default());
+
+                assert_eq!(pokemon.get_nature(), *nature);
+                assert_eq!(pokemon.get_gender_50_f(), *gender);
+                assert!(!pokemon.get_shininess(10101, 12345));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use crate::generators::Rng;
+
+const STATE_SIZE: usize = 156;
+const STATE_SIZE_32: usize = STATE_SIZE * 4;
+const POS1: usize = 122;
+const SL1: u32 = 18;
+const SL2: u32 = 1;
+const SR1: u32 = 11;
+const SR2: u32 = 1;
+const MASK: [u32; 4] = [0xDFFFFFEF, 0xDDFECB7F, 0xBFFAFFFF, 0xBFFFFFF6];
+const PARITY: [u32; 4] = [0x00000001, 0x00000000, 0x00000000, 0x13C9E684];
+
+/// Represents the SFMT-19937 SIMD-oriented fast mersenne twister http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/SFMT/
+///
+/// Gen 6 and 7 games use it for most of their randomness, usually combining two outputs into a 64-bit number.
+#[derive(Clone)]
+pub struct Sfmt {
+    /// 156 words of 128 bits, stored as 32-bit words with the lowest first
+    state: [u32; STATE_SIZE_32],
+    index: usize,
+}
+
+impl Sfmt {
+    /// Constructs a new SFMT instance with the given initial seed
+    pub fn new(initial_seed: u32) -> Self {
+        let mut state = [0u32; STATE_SIZE_32];
+        state[0] = initial_seed;
+        for i in 1..STATE_SIZE_32 {
+            let prev = state[i - 1];
+            state[i] = 0x6C078965u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(i as u32);
+        }
+
+        let mut sfmt = Sfmt {
+            state,
+            index: STATE_SIZE_32,
+        };
+        sfmt.certify_period();
+        sfmt
+    }
+
+    /// Flips a bit of the state if needed, so that the state does not fall in a short period
+    fn certify_period(&mut self) {
+        let mut inner = (0..4).fold(0u32, |inner, i| inner ^ (self.state[i] & PARITY[i]));
+        for shift in [16, 8, 4, 2, 1] {
+            inner ^= inner >> shift;
+        }
+        if inner & 1 == 1 {
+            return;
+        }
+
+        for (i, parity) in PARITY.iter().enumerate() {
+            for bit in 0..32 {
+                if parity & (1 << bit) != 0 {
+                    self.state[i] ^= 1 << bit;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn word(&self, i: usize) -> [u32; 4] {
+        [
+            self.state[i * 4],
+            self.state[i * 4 + 1],
+            self.state[i * 4 + 2],
+            self.state[i * 4 + 3],
+        ]
+    }
+
+    /// Regenerates the whole state once every output of the current state has been used
+    fn shuffle(&mut self) {
+        let mut r1 = self.word(STATE_SIZE - 2);
+        let mut r2 = self.word(STATE_SIZE - 1);
+        for i in 0..STATE_SIZE {
+            let b = self.word((i + POS1) % STATE_SIZE);
+            let next = recursion(self.word(i), b, r1, r2);
+            self.state[i * 4..i * 4 + 4].copy_from_slice(&next);
+            r1 = r2;
+            r2 = next;
+        }
+        self.index = 0;
+    }
+
+    /// Advances the twister, outputting the next u32 of the state
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= STATE_SIZE_32 {
+            self.shuffle();
+        }
+        let result = self.state[self.index];
+        self.index += 1;
+        result
+    }
+
+    /// Advances the twister by two, combining the outputs into a u64 with the first as the low half, as the Gen 6 and 7 games read it
+    pub fn next_u64(&mut self) -> u64 {
+        let low = self.next_u32() as u64;
+        let high = self.next_u32() as u64;
+        (high << 32) | low
+    }
+}
+
+/// The recursion of SFMT over 128-bit words, combining the current word with the word POS1 further and the two previously generated words
+fn recursion(a: [u32; 4], b: [u32; 4], c: [u32; 4], d: [u32; 4]) -> [u32; 4] {
+    let x = shift_left_128(a, SL2 * 8);
+    let y = shift_right_128(c, SR2 * 8);
+    let mut r = [0u32; 4];
+    for i in 0..4 {
+        r[i] = a[i] ^ x[i] ^ ((b[i] >> SR1) & MASK[i]) ^ y[i] ^ (d[i] << SL1);
+    }
+    r
+}
+
+fn to_u128(words: [u32; 4]) -> u128 {
+    words
+        .iter()
+        .rev()
+        .fold(0u128, |value, word| (value << 32) | *word as u128)
+}
+
+fn from_u128(value: u128) -> [u32; 4] {
+    [
+        value as u32,
+        (value >> 32) as u32,
+        (value >> 64) as u32,
+        (value >> 96) as u32,
+    ]
+}
+
+fn shift_left_128(words: [u32; 4], bits: u32) -> [u32; 4] {
+    from_u128(to_u128(words) << bits)
+}
+
+fn shift_right_128(words: [u32; 4], bits: u32) -> [u32; 4] {
+    from_u128(to_u128(words) >> bits)
+}
+
+impl Rng for Sfmt {
+    fn next_u32(&mut self) -> u32 {
+        Sfmt::next_u32(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sfmt_reference_sequence() -> Result<(), String> {
+        // the first outputs of the reference implementation's test seeded with 1234
+        let mut sfmt = Sfmt::new(1234);
+        assert_eq!(sfmt.next_u32(), 3440181298);
+        assert_eq!(sfmt.next_u32(), 1564997079);
+        assert_eq!(sfmt.next_u32(), 1510669302);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::generators::wild::{slot_for, GRASS_SLOT_THRESHOLDS};
+
+/// The seven Tanoby Chambers in the Sevii Islands, each of which holds its own set of Unown forms
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TanobyChamber {
+    Monean,
+    Liptoo,
+    Weepth,
+    Dilford,
+    Scufib,
+    Rixy,
+    Viapois,
+}
+
+impl TanobyChamber {
+    /// The Unown form found in each encounter slot of the chamber
+    pub fn slot_letters(&self) -> [char; 12] {
+        match self {
+            TanobyChamber::Monean => ['A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', 'A', '?'],
+            TanobyChamber::Liptoo => ['C', 'C', 'C', 'D', 'D', 'D', 'H', 'H', 'H', 'U', 'U', 'O'],
+            TanobyChamber::Weepth => ['N', 'N', 'N', 'N', 'S', 'S', 'S', 'S', 'I', 'I', 'E', 'E'],
+            TanobyChamber::Dilford => ['J', 'J', 'J', 'J', 'L', 'L', 'L', 'L', 'P', 'P', 'Q', 'R'],
+            TanobyChamber::Scufib => ['F', 'F', 'F', 'F', 'G', 'G', 'G', 'G', 'K', 'K', 'T', 'Y'],
+            TanobyChamber::Rixy => ['V', 'V', 'V', 'V', 'W', 'W', 'W', 'W', 'X', 'X', 'M', 'B'],
+            TanobyChamber::Viapois => ['Z', 'Z', 'Z', 'Z', 'Z', 'Z', 'Z', 'Z', 'Z', 'Z', 'Z', '!'],
+        }
+    }
+
+    /// The encounter slot chosen by the given random number
+    pub fn slot_for(&self, rand: u16) -> u8 {
+        slot_for(&GRASS_SLOT_THRESHOLDS, rand % 100)
+    }
+
+    /// Looks up the Unown form for the encounter slot chosen by the given random number
+    pub fn letter_for(&self, rand: u16) -> char {
+        self.slot_letters()[self.slot_for(rand) as usize]
+    }
+}
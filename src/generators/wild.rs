@@ -0,0 +1,152 @@
+use crate::generators::Rng;
+
+/// Cumulative probabilities of the 12 grass encounter slots, out of 100
+pub(crate) static GRASS_SLOT_THRESHOLDS: [u16; 12] =
+    [20, 40, 50, 60, 70, 80, 85, 90, 94, 98, 99, 100];
+/// Cumulative probabilities of the 5 surfing slots, also used by the Old Rod of Diamond, Pearl and Platinum
+static SURF_SLOT_THRESHOLDS: [u16; 5] = [60, 90, 95, 99, 100];
+/// Cumulative probabilities of the 5 fishing slots of every other rod
+static ROD_SLOT_THRESHOLDS: [u16; 5] = [40, 80, 95, 99, 100];
+
+/// The lowest and highest level of each of the five surfing or fishing slots of an area
+pub type SlotLevels = [(u8, u8); 5];
+
+/// The rods of Gen 4, each hooking a bite more often than the last
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Rod {
+    Old,
+    Good,
+    Super,
+}
+
+impl Rod {
+    /// The chance out of 100 that a pokemon bites
+    fn bite_chance(&self) -> u16 {
+        match self {
+            Rod::Old => 25,
+            Rod::Good => 50,
+            Rod::Super => 75,
+        }
+    }
+}
+
+/// The kind of Gen 4 wild encounter, which decides the RNG calls Methods J and K make before the lead and nature calls
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WildEncounter {
+    /// Grass and caves, whose slots each have a fixed level, so only the slot is rolled. It can be illustrated as [slot].
+    Grass,
+    /// Surfing, where the level is rolled within the range of the slot. It can be illustrated as [slot] [level].
+    Surfing(SlotLevels),
+    /// Fishing, where a bite is rolled first and nothing is encountered when it fails. It can be illustrated as [bite] [slot] [level].
+    Fishing(Rod, SlotLevels),
+}
+
+impl WildEncounter {
+    /// Rolls the encounter slot and level, or returns None when nothing bites.
+    ///
+    /// Method J turns random numbers into percentages by dividing them by 656 and picks levels by division,
+    /// where Method K takes both modulo.
+    pub(crate) fn roll<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        method_k: bool,
+    ) -> Option<(u8, Option<u8>)> {
+        let percent = |rand: u16| {
+            if method_k {
+                rand % 100
+            } else {
+                rand / 656
+            }
+        };
+
+        let (thresholds, levels): (&[u16], _) = match *self {
+            WildEncounter::Grass => {
+                let slot = slot_for(&GRASS_SLOT_THRESHOLDS, percent(rng.next_u16()));
+                return Some((slot, None));
+            }
+            WildEncounter::Surfing(levels) => (&SURF_SLOT_THRESHOLDS, levels),
+            WildEncounter::Fishing(rod, levels) => {
+                if percent(rng.next_u16()) >= rod.bite_chance() {
+                    return None;
+                }
+                if rod == Rod::Old && !method_k {
+                    (&SURF_SLOT_THRESHOLDS, levels)
+                } else {
+                    (&ROD_SLOT_THRESHOLDS, levels)
+                }
+            }
+        };
+
+        let slot = slot_for(thresholds, percent(rng.next_u16()));
+        let (min, max) = levels[slot as usize];
+        let range = (max - min + 1) as u16;
+        let rand = rng.next_u16();
+        let offset = if method_k {
+            rand % range
+        } else {
+            rand / (0xFFFF / range + 1)
+        };
+
+        Some((slot, Some(min + offset as u8)))
+    }
+}
+
+/// The slot whose cumulative probability first exceeds the percentage
+pub(crate) fn slot_for(thresholds: &[u16], percent: u16) -> u8 {
+    thresholds
+        .iter()
+        .position(|threshold| percent < *threshold)
+        .unwrap() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{GenerationMethod, Lead, LinearCongruential, Method};
+
+    const LEVELS: SlotLevels = [(20, 30), (21, 31), (25, 35), (30, 40), (35, 45)];
+
+    fn generate(method: Method, seed: u32) -> Option<(u32, Option<u8>, Option<u8>)> {
+        let generated = method.generate(&mut LinearCongruential::new(seed))?;
+        Some((generated.pokemon.pid, generated.slot, generated.level))
+    }
+
+    #[test]
+    fn test_surfing_rolls_slot_and_level() -> Result<(), String> {
+        // 0x01DB / 656 is 0 and 0x7B06 / (0xFFFF / 11 + 1) is 5, where 0x01DB % 100 is 75 and 0x7B06 % 11 is 1
+        let surfing = WildEncounter::Surfing(LEVELS);
+        assert_eq!(
+            generate(Method::J(Lead::None, surfing), 0x1A56B091),
+            Some((0x5CC4E470, Some(0), Some(25)))
+        );
+        assert_eq!(
+            generate(Method::K(Lead::None, surfing), 0x1A56B091),
+            Some((0x8132C147, Some(1), Some(22)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fishing_rolls_bite() -> Result<(), String> {
+        // 0x01DB / 656 is 0, so a pokemon bites and the slot is rolled from 0x7B06
+        let good_rod = WildEncounter::Fishing(Rod::Good, LEVELS);
+        assert_eq!(
+            generate(Method::J(Lead::None, good_rod), 0x1A56B091),
+            Some((0xD6B25CEE, Some(1), Some(24)))
+        );
+        // the Old Rod of Diamond, Pearl and Platinum shares the surfing slots
+        let old_rod = WildEncounter::Fishing(Rod::Old, LEVELS);
+        assert_eq!(
+            generate(Method::J(Lead::None, old_rod), 0x1A56B091),
+            Some((0xD6B25CEE, Some(0), Some(23)))
+        );
+
+        // 0x01DB % 100 is 75, so nothing bites in HeartGold and SoulSilver
+        assert_eq!(generate(Method::K(Lead::None, good_rod), 0x1A56B091), None);
+        assert_eq!(
+            generate(Method::K(Lead::None, good_rod), 0x12345678),
+            Some((0x82C38F50, Some(0), Some(28)))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use crate::generators::{Channel, Game, LinearCongruential};
+use crate::pokemon::{IndividualValues, Pokemon};
+
+/// The most PID rerolls walked back through when looking for the nature call of a wild encounter
+const MAX_WILD_REROLLS: usize = 1000;
+
+/// A method which can generate a PID and IVs
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Origin {
+    Method1,
+    Method2,
+    Method4,
+    /// Wild encounters. Three RNG calls decide the encounter slot, level and nature, then PIDs are rerolled until the nature matches.
+    MethodH1,
+    MethodH2,
+    MethodH4,
+    /// Two consecutive RNG calls for the IVs. Inherited IVs are not accounted for, so only eggs which inherited nothing are found.
+    /// The IVs are not linked to the PID, so a few seeds match almost any spread and this origin is only checked when the encounter is an egg.
+    Egg,
+    ColosseumXD,
+    Channel,
+    /// Method 1, but the game only saves the HP IV and the low 3 bits of the Attack IV, leaving every other IV at 0.
+    Roamer,
+}
+
+/// The kind of encounter a pokemon came from, used to narrow the origins that are checked
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Encounter {
+    Static,
+    Wild,
+    Egg,
+    Roamer,
+    GameCube,
+}
+
+impl Origin {
+    fn possible_for(&self, game: &Option<Game>, encounter: &Option<Encounter>) -> bool {
+        let from_gamecube = matches!(self, Origin::ColosseumXD | Origin::Channel);
+        if game.is_some() && from_gamecube {
+            return false;
+        }
+
+        let expected = match self {
+            Origin::Method1 | Origin::Method2 | Origin::Method4 => Encounter::Static,
+            Origin::MethodH1 | Origin::MethodH2 | Origin::MethodH4 => Encounter::Wild,
+            Origin::Egg => Encounter::Egg,
+            Origin::Roamer => Encounter::Roamer,
+            Origin::ColosseumXD | Origin::Channel => Encounter::GameCube,
+        };
+
+        match encounter {
+            Some(encounter) => *encounter == expected,
+            // IV-only matches say nothing about legality unless an egg is expected
+            None => expected != Encounter::Egg,
+        }
+    }
+}
+
+/// An origin which produces the checked pokemon, along with the seed the rng held before generation started
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LegalityMatch {
+    pub origin: Origin,
+    pub seed: u32,
+}
+
+/// Finds every generation method and originating seed which can produce the given PID and IVs.
+///
+/// Passing a game excludes the GameCube origins, and passing an encounter type only checks the origins of that encounter.
+/// Egg origins are only checked when the encounter is `Encounter::Egg`.
+/// A pokemon with no matches could not have been generated by any of the supported methods.
+pub fn find_origins(
+    pokemon: &Pokemon,
+    game: Option<Game>,
+    encounter: Option<Encounter>,
+) -> Vec<LegalityMatch> {
+    let possible = |origin: Origin| origin.possible_for(&game, &encounter);
+    let mut matches = Vec::new();
+
+    for seed in pid_seeds(pokemon.pid) {
+        let [iv1, iv2, iv3] = ivs_after_pid(seed);
+        let patterns = [
+            (Origin::Method1, Origin::MethodH1, (iv1, iv2)),
+            (Origin::Method2, Origin::MethodH2, (iv2, iv3)),
+            (Origin::Method4, Origin::MethodH4, (iv1, iv3)),
+        ];
+
+        for (origin, wild_origin, (n1, n2)) in patterns.iter() {
+            if IndividualValues::new_from_numbers(*n1, *n2) != pokemon.ivs {
+                continue;
+            }
+            if possible(*origin) {
+                matches.push(LegalityMatch {
+                    origin: *origin,
+                    seed,
+                });
+            }
+            if possible(*wild_origin) {
+                for wild_seed in wild_seeds(seed, pokemon) {
+                    matches.push(LegalityMatch {
+                        origin: *wild_origin,
+                        seed: wild_seed,
+                    });
+                }
+            }
+        }
+
+        let roamer_ivs = IndividualValues::new_from_numbers(iv1 & 0xFF, 0);
+        if possible(Origin::Roamer) && roamer_ivs == pokemon.ivs {
+            matches.push(LegalityMatch {
+                origin: Origin::Roamer,
+                seed,
+            });
+        }
+    }
+
+    if possible(Origin::Egg) {
+        for seed in iv_seeds(&pokemon.ivs, LinearCongruential::new) {
+            matches.push(LegalityMatch {
+                origin: Origin::Egg,
+                seed,
+            });
+        }
+    }
+
+    if possible(Origin::ColosseumXD) {
+        for seed in iv_seeds(&pokemon.ivs, LinearCongruential::new_xd) {
+            let mut rng = LinearCongruential::new_xd(seed);
+            // IVs, IVs, ability
+            for _ in 0..3 {
+                rng.step();
+            }
+            let high = rng.next_u16() as u32;
+            let low = rng.next_u16() as u32;
+            if (high << 16) | low == pokemon.pid {
+                matches.push(LegalityMatch {
+                    origin: Origin::ColosseumXD,
+                    seed,
+                });
+            }
+        }
+    }
+
+    if possible(Origin::Channel) {
+        for seed in Channel::seeds_for(pokemon) {
+            matches.push(LegalityMatch {
+                origin: Origin::Channel,
+                seed,
+            });
+        }
+    }
+
+    matches
+}
+
+/// Finds every seed from which two calls to the LCRNG produce the given PID, with the first call being the low half.
+fn pid_seeds(pid: u32) -> Vec<u32> {
+    let low = pid & 0xFFFF;
+    let high = (pid >> 16) as u16;
+    let mut seeds = Vec::new();
+
+    for bottom in 0..=0xFFFFu32 {
+        let mut rng = LinearCongruential::new((low << 16) | bottom);
+        if rng.next_u16() == high {
+            rng.prev_u32();
+            seeds.push(rng.prev_u32());
+        }
+    }
+
+    seeds
+}
+
+/// The three random numbers following the PID, which hold the IVs for Methods 1, 2 and 4
+fn ivs_after_pid(seed: u32) -> [u16; 3] {
+    let mut rng = LinearCongruential::new(seed);
+    rng.step();
+    rng.step();
+    [rng.next_u16(), rng.next_u16(), rng.next_u16()]
+}
+
+/// Walks back from the seed before the PID calls of a wild encounter, looking for a nature call which matches the PID's nature.
+///
+/// Each time the preceding calls form a PID with a different nature, they may have been a rejected reroll, so the walk continues past them.
+fn wild_seeds(pid_seed: u32, pokemon: &Pokemon) -> Vec<u32> {
+    let nature = pokemon.get_nature() as u16;
+    let mut rng = LinearCongruential::new(pid_seed);
+    let mut seeds = Vec::new();
+
+    for _ in 0..MAX_WILD_REROLLS {
+        let nature_seed = rng.seed();
+        if (nature_seed >> 16) as u16 % 25 == nature {
+            // slot, level and nature calls
+            rng.prev_u32();
+            rng.prev_u32();
+            seeds.push(rng.prev_u32());
+            rng.set_seed(nature_seed);
+        }
+
+        let high = nature_seed >> 16;
+        let low = rng.prev_u32() >> 16;
+        let rejected = Pokemon::new((high << 16) | low, IndividualValues::default());
+        if rejected.get_nature() == pokemon.get_nature() {
+            break;
+        }
+        rng.prev_u32();
+    }
+
+    seeds
+}
+
+/// Finds every seed from which two calls to the given rng produce the IVs, ignoring the unused top bit of each call.
+fn iv_seeds(ivs: &IndividualValues, new_rng: fn(u32) -> LinearCongruential) -> Vec<u32> {
+    let (n1, n2) = ivs.to_numbers();
+    let mut seeds = Vec::new();
+
+    for top_bit in 0..2u32 {
+        for bottom in 0..=0xFFFFu32 {
+            let mut rng = new_rng((top_bit << 31) | (n1 as u32) << 16 | bottom);
+            if rng.next_u16() & 0x7FFF == n2 {
+                rng.prev_u32();
+                seeds.push(rng.prev_u32());
+            }
+        }
+    }
+
+    seeds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Generator;
+
+    fn has_match(matches: &[LegalityMatch], origin: Origin, seed: u32) -> bool {
+        matches.contains(&LegalityMatch { origin, seed })
+    }
+
+    #[test]
+    fn test_method_1_and_2_origins() -> Result<(), String> {
+        let pokemon = LinearCongruential::new(0).method_1();
+        let matches = find_origins(&pokemon, Some(Game::Emerald), Some(Encounter::Static));
+        assert!(has_match(&matches, Origin::Method1, 0));
+
+        let pokemon = LinearCongruential::new(0x560B9CE3u32).method_2();
+        let matches = find_origins(&pokemon, None, None);
+        assert!(has_match(&matches, Origin::Method2, 0x560B9CE3u32));
+        assert!(matches.iter().all(|m| m.origin != Origin::Channel));
+        Ok(())
+    }
+
+    #[test]
+    fn test_wild_origin() -> Result<(), String> {
+        let seed = 0x12345678u32;
+        let mut rng = LinearCongruential::new(seed);
+        rng.step();
+        rng.step();
+        let nature = rng.next_u16() % 25;
+        let pid = loop {
+            let pid = rng.generate_pid();
+            if pid % 25 == nature as u32 {
+                break pid;
+            }
+        };
+        let ivs = IndividualValues::new_from_numbers(rng.next_u16(), rng.next_u16());
+        let pokemon = Pokemon::new(pid, ivs);
+
+        let matches = find_origins(&pokemon, None, Some(Encounter::Wild));
+        assert!(has_match(&matches, Origin::MethodH1, seed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_colosseum_xd_origin() -> Result<(), String> {
+        let seed = 0xCAFEF00Du32;
+        let mut rng = LinearCongruential::new_xd(seed);
+        let ivs = IndividualValues::new_from_numbers(rng.next_u16(), rng.next_u16());
+        rng.step();
+        let high = rng.next_u16() as u32;
+        let low = rng.next_u16() as u32;
+        let pokemon = Pokemon::new((high << 16) | low, ivs);
+
+        let matches = find_origins(&pokemon, None, Some(Encounter::GameCube));
+        assert!(has_match(&matches, Origin::ColosseumXD, seed));
+        assert!(find_origins(&pokemon, Some(Game::Ruby), None)
+            .iter()
+            .all(|m| m.origin != Origin::ColosseumXD));
+        Ok(())
+    }
+
+    #[test]
+    fn test_egg_origin_requires_egg_encounter() -> Result<(), String> {
+        let mut rng = LinearCongruential::new(0xDEADBEEF);
+        let ivs = IndividualValues::new_from_numbers(rng.next_u16(), rng.next_u16());
+        let pokemon = Pokemon::new(0x12345678, ivs);
+
+        assert!(find_origins(&pokemon, None, None).is_empty());
+        assert!(has_match(
+            &find_origins(&pokemon, None, Some(Encounter::Egg)),
+            Origin::Egg,
+            0xDEADBEEF
+        ));
+        Ok(())
+    }
+}
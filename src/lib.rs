@@ -1,9 +1,17 @@
+pub mod emerald;
+pub mod events;
 pub mod filter;
 pub mod generators;
+pub mod legality;
+pub mod pk3;
 pub mod pokemon;
 pub mod profile;
+pub mod save;
 pub mod search;
+pub mod seed;
+pub mod showdown;
+pub mod timer;
 
 pub use filter::Filter;
 pub use profile::Profile;
-pub use search::Searcher;
+pub use search::{EggSearcher, Searcher, SeedSearchError, SeedSearcher};
@@ -1,10 +1,21 @@
+use std::env;
+use std::io;
+use std::time::Duration;
+
 use masuda::{
     filter::{StatComparison, StatFilter},
     generators::{Game, Method},
+    timer::{Console, Stage, Timer},
     Filter, Profile, Searcher,
 };
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("timer") {
+        run_timer(&args[1..]);
+        return;
+    }
+
     // create an searcher instance with game, method, and max frames
     let mut searcher = Searcher::new(Game::Emerald, Method::One, 100000000000);
     // create a profile with a tid and an sid
@@ -22,3 +33,23 @@ fn main() {
         println!("{:?}", result);
     }
 }
+
+/// Runs a two stage GBA timer: `timer <seed ms> <target frame> [<frame hit last attempt>]`
+fn run_timer(args: &[String]) {
+    let numbers: Vec<u64> = args.iter().filter_map(|arg| arg.parse().ok()).collect();
+    if numbers.len() < 2 {
+        eprintln!("usage: masuda timer <seed ms> <target frame> [<frame hit last attempt>]");
+        return;
+    }
+
+    let mut timer = Timer::new(Console::Gba)
+        .with_stage(Stage::Duration(Duration::from_millis(numbers[0])))
+        .with_stage(Stage::Frames(numbers[1]));
+    if let Some(hit) = numbers.get(2) {
+        timer.calibrate(1, numbers[1], *hit);
+    }
+
+    if let Err(e) = timer.countdown(&mut io::stdout()) {
+        eprintln!("{}", e);
+    }
+}
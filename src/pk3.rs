@@ -0,0 +1,288 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::pokemon::{IndividualValues, Pokemon};
+use crate::Profile;
+
+/// Size of a pokemon stored in the PC
+pub const PK3_BOX_SIZE: usize = 80;
+/// Size of a pokemon in the party, which appends its current stats to the boxed structure
+pub const PK3_PARTY_SIZE: usize = 100;
+
+/// Language of exported pokemon, English
+const LANGUAGE_ENGLISH: u8 = 2;
+/// Flag marking that the species field of the pokemon is set
+const FLAG_HAS_SPECIES: u8 = 0x02;
+
+/// The substructure stored at each position, indexed by PID modulo 24, where 0 to 3 are the Growth, Attacks, EVs and Misc substructures.
+/// The rows run from GAEM to MEAG.
+const SUBSTRUCTURE_ORDERS: [[usize; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 2, 3, 1],
+    [0, 3, 1, 2],
+    [0, 3, 2, 1],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [1, 2, 0, 3],
+    [1, 2, 3, 0],
+    [1, 3, 0, 2],
+    [1, 3, 2, 0],
+    [2, 0, 1, 3],
+    [2, 0, 3, 1],
+    [2, 1, 0, 3],
+    [2, 1, 3, 0],
+    [2, 3, 0, 1],
+    [2, 3, 1, 0],
+    [3, 0, 1, 2],
+    [3, 0, 2, 1],
+    [3, 1, 0, 2],
+    [3, 1, 2, 0],
+    [3, 2, 0, 1],
+    [3, 2, 1, 0],
+];
+
+const GROWTH: usize = 0;
+const MISC: usize = 3;
+
+#[derive(Debug)]
+pub enum Pk3Error {
+    Io(io::Error),
+    /// The data is neither the 80 bytes of a boxed pokemon nor the 100 bytes of a party pokemon
+    InvalidSize(usize),
+    /// The data holds no pokemon, as both the PID and the OT id are 0
+    Empty,
+    /// The decrypted substructures do not add up to the stored checksum
+    InvalidChecksum,
+}
+
+impl fmt::Display for Pk3Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Pk3Error::Io(e) => write!(f, "could not read pk3: {}", e),
+            Pk3Error::InvalidSize(size) => write!(
+                f,
+                "expected a {} or {} byte pk3, got {} bytes",
+                PK3_BOX_SIZE, PK3_PARTY_SIZE, size
+            ),
+            Pk3Error::Empty => write!(f, "pk3 holds no pokemon"),
+            Pk3Error::InvalidChecksum => write!(f, "pk3 checksum does not match its data"),
+        }
+    }
+}
+
+impl std::error::Error for Pk3Error {}
+
+impl From<io::Error> for Pk3Error {
+    fn from(e: io::Error) -> Self {
+        Pk3Error::Io(e)
+    }
+}
+
+/// A pokemon in the Gen 3 storage format, as read and written by PKHeX
+pub struct Pk3 {
+    pub pokemon: Pokemon,
+    pub tid: u16,
+    pub sid: u16,
+    /// The species in the Gen 3 internal index, which differs from the national dex number for Hoenn pokemon
+    pub species: u16,
+}
+
+impl Pk3 {
+    /// Constructs a pk3 for a generated pokemon, owned by the trainer of the given profile
+    pub fn new(pokemon: Pokemon, profile: &Profile, species: u16) -> Self {
+        Pk3 {
+            pokemon,
+            tid: profile.tid,
+            sid: profile.sid,
+            species,
+        }
+    }
+
+    /// Reads and decrypts the pk3 file at the given path
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Pk3Error> {
+        let bytes = fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Decrypts a pokemon from its raw bytes.
+    ///
+    /// The 48 bytes of substructures are encrypted by XORing each word with the PID XOR the OT id, and shuffled into one of 24 orders by the PID.
+    /// The IVs live in the second word of the Misc substructure, 5 bits each in the order HP, Atk, Def, Spe, SpA, SpD.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Pk3Error> {
+        if data.len() != PK3_BOX_SIZE && data.len() != PK3_PARTY_SIZE {
+            return Err(Pk3Error::InvalidSize(data.len()));
+        }
+
+        let pid = read_u32(data, 0x00);
+        let ot_id = read_u32(data, 0x04);
+        if pid == 0 && ot_id == 0 {
+            return Err(Pk3Error::Empty);
+        }
+
+        let decrypted = crypt(&data[0x20..0x50], pid ^ ot_id);
+        if checksum(&decrypted) != read_u16(data, 0x1C) {
+            return Err(Pk3Error::InvalidChecksum);
+        }
+
+        let offset_of = |substructure: usize| {
+            let position = SUBSTRUCTURE_ORDERS[(pid % 24) as usize]
+                .iter()
+                .position(|s| *s == substructure)
+                .unwrap();
+            position * 12
+        };
+        let species = read_u16(&decrypted, offset_of(GROWTH));
+        let iv_word = read_u32(&decrypted, offset_of(MISC) + 4);
+        let iv = |shift: u32| ((iv_word >> shift) & 0x1F) as u8;
+
+        Ok(Pk3 {
+            pokemon: Pokemon::new(
+                pid,
+                IndividualValues::new(iv(0), iv(5), iv(10), iv(20), iv(25), iv(15)),
+            ),
+            tid: ot_id as u16,
+            sid: (ot_id >> 16) as u16,
+            species,
+        })
+    }
+
+    /// Encrypts the pokemon into the 80 byte boxed format. Fields masuda does not track, such as the nickname and moves, are left blank.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let pid = self.pokemon.pid;
+        let ot_id = (self.sid as u32) << 16 | self.tid as u32;
+        let ivs = &self.pokemon.ivs;
+        let iv_word = ivs.hp as u32
+            | (ivs.atk as u32) << 5
+            | (ivs.def as u32) << 10
+            | (ivs.spe as u32) << 15
+            | (ivs.spa as u32) << 20
+            | (ivs.spd as u32) << 25
+            | (self.pokemon.get_ability() as u32) << 31;
+
+        let mut substructures = [[0u8; 12]; 4];
+        substructures[GROWTH][0..2].copy_from_slice(&self.species.to_le_bytes());
+        substructures[MISC][4..8].copy_from_slice(&iv_word.to_le_bytes());
+
+        let decrypted: Vec<u8> = SUBSTRUCTURE_ORDERS[(pid % 24) as usize]
+            .iter()
+            .flat_map(|substructure| substructures[*substructure])
+            .collect();
+
+        let mut data = vec![0u8; PK3_BOX_SIZE];
+        data[0x00..0x04].copy_from_slice(&pid.to_le_bytes());
+        data[0x04..0x08].copy_from_slice(&ot_id.to_le_bytes());
+        // nickname and OT name are left terminated
+        data[0x08] = 0xFF;
+        data[0x12] = LANGUAGE_ENGLISH;
+        data[0x13] = FLAG_HAS_SPECIES;
+        data[0x14] = 0xFF;
+        data[0x1C..0x1E].copy_from_slice(&checksum(&decrypted).to_le_bytes());
+        data[0x20..0x50].copy_from_slice(&crypt(&decrypted, pid ^ ot_id));
+        data
+    }
+
+    /// Encrypts the pokemon and writes it to a pk3 file at the given path
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), Pk3Error> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+}
+
+/// XORs each 32-bit word with the key, which both encrypts and decrypts the substructures
+fn crypt(data: &[u8], key: u32) -> Vec<u8> {
+    data.chunks(4)
+        .flat_map(|word| (read_u32(word, 0) ^ key).to_le_bytes())
+        .collect()
+}
+
+/// The checksum is the sum of the decrypted substructures as 16-bit words
+fn checksum(decrypted: &[u8]) -> u16 {
+    decrypted
+        .chunks(2)
+        .fold(0u16, |sum, half| sum.wrapping_add(read_u16(half, 0)))
+}
+
+pub(crate) fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pk3_round_trip() -> Result<(), String> {
+        let profile = Profile::new(10101, 12345);
+
+        for pid in [0xB58F0B2Au32, 0x7E47A651u32, 0x00000017u32].iter() {
+            let ivs = IndividualValues::new(31, 30, 29, 28, 27, 26);
+            let pk3 = Pk3::new(Pokemon::new(*pid, ivs), &profile, 385);
+            let bytes = pk3.to_bytes();
+            assert_eq!(bytes.len(), PK3_BOX_SIZE);
+
+            let read = Pk3::from_bytes(&bytes).unwrap();
+            assert_eq!(read.pokemon.pid, *pid);
+            assert_eq!(
+                read.pokemon.ivs,
+                IndividualValues::new(31, 30, 29, 28, 27, 26)
+            );
+            assert_eq!((read.tid, read.sid, read.species), (10101, 12345, 385));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pk3_known_bytes() -> Result<(), String> {
+        // PID 3 stores its substructures in the order GEMA, which is not its own inverse
+        let bytes: [u8; PK3_BOX_SIZE] = [
+            0x03, 0x00, 0x00, 0x00, 0x75, 0x27, 0x39, 0x30, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x9E, 0x2D, 0x00, 0x00, 0x77, 0x27, 0x39, 0x30, 0x76, 0x27, 0x39, 0x30, 0x76, 0x27,
+            0x39, 0x30, 0x76, 0x27, 0x39, 0x30, 0x76, 0x27, 0x39, 0x30, 0x76, 0x27, 0x39, 0x30,
+            0x76, 0x27, 0x39, 0x30, 0xA9, 0x50, 0x87, 0x85, 0x76, 0x27, 0x39, 0x30, 0x76, 0x27,
+            0x39, 0x30, 0x76, 0x27, 0x39, 0x30, 0x76, 0x27, 0x39, 0x30,
+        ];
+
+        let pk3 = Pk3::from_bytes(&bytes).unwrap();
+        assert_eq!(pk3.pokemon.pid, 3);
+        assert_eq!(
+            pk3.pokemon.ivs,
+            IndividualValues::new(31, 30, 29, 27, 26, 28)
+        );
+        assert_eq!((pk3.tid, pk3.sid, pk3.species), (10101, 12345, 1));
+        assert_eq!(pk3.to_bytes(), bytes.to_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pk3_rejects_tampered_data() -> Result<(), String> {
+        let profile = Profile::new(10101, 12345);
+        let pk3 = Pk3::new(Pokemon::new(1, IndividualValues::default()), &profile, 1);
+        let mut bytes = pk3.to_bytes();
+        bytes[0x30] ^= 0x01;
+
+        assert!(matches!(
+            Pk3::from_bytes(&bytes),
+            Err(Pk3Error::InvalidChecksum)
+        ));
+        assert!(matches!(
+            Pk3::from_bytes(&[0u8; PK3_BOX_SIZE]),
+            Err(Pk3Error::Empty)
+        ));
+        assert!(matches!(
+            Pk3::from_bytes(&bytes[..10]),
+            Err(Pk3Error::InvalidSize(10))
+        ));
+        Ok(())
+    }
+}
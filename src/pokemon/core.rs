@@ -2,7 +2,10 @@ use std::fmt;
 
 use std::convert::TryFrom;
 
-use crate::pokemon::{Gender, IndividualValues, Nature, NATURES};
+use crate::pokemon::{
+    Gender, IndividualValues, Nature, SpindaSpots, WurmpleEvolution, CHARACTERISTICS, NATURES,
+    UNOWN_LETTERS,
+};
 
 pub struct Pokemon {
     pub pid: u32,
@@ -59,8 +62,61 @@ impl Pokemon {
         Gender::Male
     }
 
+    /// The form of an Unown is determined by concatenating the last 2 bits of each byte of the PID, then computing modulo 28.
+    /// The resulting index is then looked up in the UNOWN_LETTERS const table.
+    pub fn get_unown_form(&self) -> char {
+        let letter = ((self.pid & 0x3000000) >> 18)
+            | ((self.pid & 0x30000) >> 12)
+            | ((self.pid & 0x300) >> 6)
+            | (self.pid & 0x3);
+        UNOWN_LETTERS[(letter % 28) as usize]
+    }
+
+    /// Wurmple evolves into Silcoon or Cascoon depending on the high 16 bits of its PID modulo 10.
+    /// A remainder below 5 evolves into Silcoon, and anything else into Cascoon.
+    pub fn get_wurmple_evolution(&self) -> WurmpleEvolution {
+        if (self.pid >> 16) % 10 < 5 {
+            return WurmpleEvolution::Silcoon;
+        }
+        WurmpleEvolution::Cascoon
+    }
+
+    /// Each byte of the PID places one of Spinda's four spots, starting from the lowest byte.
+    /// The low nibble of the byte is the x offset of the spot and the high nibble is the y offset.
+    pub fn get_spinda_spots(&self) -> SpindaSpots {
+        let mut spots = [(0u8, 0u8); 4];
+        for (i, spot) in spots.iter_mut().enumerate() {
+            let byte = (self.pid >> (8 * i)) as u8;
+            *spot = (byte & 0xF, byte >> 4);
+        }
+        SpindaSpots::new(spots)
+    }
+
+    /// The characteristic of a pokemon is decided by its highest IV. Ties are broken by starting at the stat given by the PID modulo 6,
+    /// in the order HP, Attack, Defense, Speed, Special Attack, Special Defense, and the value of the IV modulo 5 picks the text.
+    /// Gen 3 does not display characteristics, but they are derived from the same hidden values once the pokemon is transferred.
+    pub fn get_characteristic(&self) -> &'static str {
+        let ivs = [
+            self.ivs.hp,
+            self.ivs.atk,
+            self.ivs.def,
+            self.ivs.spe,
+            self.ivs.spa,
+            self.ivs.spd,
+        ];
+        let highest = *ivs.iter().max().unwrap();
+        let start = (self.pid % 6) as usize;
+        let stat = (0..6)
+            .map(|offset| (start + offset) % 6)
+            .find(|stat| ivs[*stat] == highest)
+            .unwrap();
+
+        CHARACTERISTICS[stat * 5 + (highest % 5) as usize]
+    }
+
     // shininess is determined by the process described here:
     // https://www.smogon.com/ingame/rng/pid_iv_creation#how_shiny
+    #[allow(clippy::manual_is_multiple_of)]
     pub fn get_shininess(&self, tid: u16, sid: u16) -> bool {
         let hid = (self.pid >> 16) as u16; // 16 highest bits
         let lid = (self.pid & 65535) as u16; // 16 lowest bits
@@ -102,6 +158,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_unown_form() -> Result<(), String> {
+        let ivs = IndividualValues::default();
+        assert_eq!(Pokemon::new(0x00000000u32, ivs).get_unown_form(), 'A');
+
+        let ivs = IndividualValues::default();
+        assert_eq!(Pokemon::new(0x03030303u32, ivs).get_unown_form(), 'D');
+
+        let ivs = IndividualValues::default();
+        assert_eq!(Pokemon::new(0x00000101u32, ivs).get_unown_form(), 'F');
+        Ok(())
+    }
+
+    #[test]
+    fn test_wurmple_evolution() -> Result<(), String> {
+        let ivs = IndividualValues::default();
+        let p = Pokemon::new(0x00040000u32, ivs);
+        assert_eq!(p.get_wurmple_evolution(), WurmpleEvolution::Silcoon);
+
+        let ivs = IndividualValues::default();
+        let p = Pokemon::new(0x00090000u32, ivs);
+        assert_eq!(p.get_wurmple_evolution(), WurmpleEvolution::Cascoon);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spinda_spots() -> Result<(), String> {
+        let ivs = IndividualValues::default();
+        let p = Pokemon::new(0x12345678u32, ivs);
+        let expected = SpindaSpots::new([(8, 7), (6, 5), (4, 3), (2, 1)]);
+        assert_eq!(p.get_spinda_spots(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_characteristic_tie_break() -> Result<(), String> {
+        let ivs = IndividualValues::new(31, 31, 0, 0, 0, 31);
+        assert_eq!(
+            Pokemon::new(0, ivs).get_characteristic(),
+            "Takes plenty of siestas"
+        );
+
+        let ivs = IndividualValues::new(31, 31, 0, 0, 0, 31);
+        assert_eq!(Pokemon::new(2, ivs).get_characteristic(), "Alert to sounds");
+        Ok(())
+    }
+
     #[test]
     fn test_shininess_false() -> Result<(), String> {
         let pid = 0xC58F0B2Au32;
@@ -0,0 +1,63 @@
+use std::fmt;
+
+pub static HIDDEN_POWER_TYPES: [HiddenPowerType; 16] = [
+    HiddenPowerType::Fighting,
+    HiddenPowerType::Flying,
+    HiddenPowerType::Poison,
+    HiddenPowerType::Ground,
+    HiddenPowerType::Rock,
+    HiddenPowerType::Bug,
+    HiddenPowerType::Ghost,
+    HiddenPowerType::Steel,
+    HiddenPowerType::Fire,
+    HiddenPowerType::Water,
+    HiddenPowerType::Grass,
+    HiddenPowerType::Electric,
+    HiddenPowerType::Psychic,
+    HiddenPowerType::Ice,
+    HiddenPowerType::Dragon,
+    HiddenPowerType::Dark,
+];
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum HiddenPowerType {
+    Fighting = 0,
+    Flying = 1,
+    Poison = 2,
+    Ground = 3,
+    Rock = 4,
+    Bug = 5,
+    Ghost = 6,
+    Steel = 7,
+    Fire = 8,
+    Water = 9,
+    Grass = 10,
+    Electric = 11,
+    Psychic = 12,
+    Ice = 13,
+    Dragon = 14,
+    Dark = 15,
+}
+
+impl fmt::Display for HiddenPowerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HiddenPowerType::Fighting => write!(f, "Fighting"),
+            HiddenPowerType::Flying => write!(f, "Flying"),
+            HiddenPowerType::Poison => write!(f, "Poison"),
+            HiddenPowerType::Ground => write!(f, "Ground"),
+            HiddenPowerType::Rock => write!(f, "Rock"),
+            HiddenPowerType::Bug => write!(f, "Bug"),
+            HiddenPowerType::Ghost => write!(f, "Ghost"),
+            HiddenPowerType::Steel => write!(f, "Steel"),
+            HiddenPowerType::Fire => write!(f, "Fire"),
+            HiddenPowerType::Water => write!(f, "Water"),
+            HiddenPowerType::Grass => write!(f, "Grass"),
+            HiddenPowerType::Electric => write!(f, "Electric"),
+            HiddenPowerType::Psychic => write!(f, "Psychic"),
+            HiddenPowerType::Ice => write!(f, "Ice"),
+            HiddenPowerType::Dragon => write!(f, "Dragon"),
+            HiddenPowerType::Dark => write!(f, "Dark"),
+        }
+    }
+}
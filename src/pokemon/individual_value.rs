@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::pokemon::{HiddenPowerType, HIDDEN_POWER_TYPES};
+
 #[derive(PartialEq, Default)]
 pub struct IndividualValues {
     pub hp: u8,
@@ -62,6 +64,49 @@ impl IndividualValues {
             spe,
         }
     }
+
+    /// Hidden Power's type is decided by the lowest bit of each IV. The bits are combined in the order HP, Atk, Def, Spe, SpA, SpD into a number between 0 and 63,
+    /// which is scaled down to one of the 16 types in the HIDDEN_POWER_TYPES const table.
+    ///
+    /// ```
+    /// # use masuda::pokemon::{HiddenPowerType, IndividualValues};
+    /// let ivs = IndividualValues::new(31, 30, 31, 30, 31, 30);
+    /// assert_eq!(ivs.get_hidden_power_type(), HiddenPowerType::Fire);
+    /// assert_eq!(ivs.get_hidden_power_power(), 70);
+    /// ```
+    pub fn get_hidden_power_type(&self) -> HiddenPowerType {
+        let bits = self.hidden_power_bits(0);
+        HIDDEN_POWER_TYPES[(bits * 15 / 63) as usize]
+    }
+
+    /// Hidden Power's base power is decided by the second lowest bit of each IV, combined in the same order as for the type and scaled to between 30 and 70.
+    pub fn get_hidden_power_power(&self) -> u8 {
+        let bits = self.hidden_power_bits(1);
+        (bits * 40 / 63 + 30) as u8
+    }
+
+    fn hidden_power_bits(&self, bit: u8) -> u32 {
+        [self.hp, self.atk, self.def, self.spe, self.spa, self.spd]
+            .iter()
+            .enumerate()
+            .map(|(i, iv)| (((iv >> bit) & 1) as u32) << i)
+            .sum()
+    }
+
+    /// Packs the IVs back into the two random numbers they were generated from, the reverse of `new_from_numbers`.
+    ///
+    /// The top bit of each number does not contribute to the IVs, so it is always left unset.
+    ///
+    /// ```
+    /// # use masuda::pokemon::IndividualValues;
+    /// let ivs = IndividualValues::new(19, 17, 20, 3, 25, 16);
+    /// assert_eq!(ivs.to_numbers(), (0x5233u16, 0x6470u16));
+    /// ```
+    pub fn to_numbers(&self) -> (u16, u16) {
+        let n1 = self.hp as u16 | (self.atk as u16) << 5 | (self.def as u16) << 10;
+        let n2 = self.spe as u16 | (self.spa as u16) << 5 | (self.spd as u16) << 10;
+        (n1, n2)
+    }
 }
 
 impl fmt::Debug for IndividualValues {
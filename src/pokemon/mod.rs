@@ -1,9 +1,19 @@
+mod characteristic;
 mod core;
 mod gender;
+mod hidden_power;
 mod individual_value;
 mod nature;
+mod spinda;
+mod unown;
+mod wurmple;
 
+pub use self::characteristic::CHARACTERISTICS;
 pub use self::core::Pokemon;
 pub use self::gender::Gender;
+pub use self::hidden_power::{HiddenPowerType, HIDDEN_POWER_TYPES};
 pub use self::individual_value::IndividualValues;
 pub use self::nature::{Nature, NATURES};
+pub use self::spinda::SpindaSpots;
+pub use self::unown::UNOWN_LETTERS;
+pub use self::wurmple::WurmpleEvolution;
@@ -0,0 +1,11 @@
+/// The positions of the four spots on a Spinda's face, each given as an (x, y) offset between 0 and 15
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpindaSpots {
+    pub spots: [(u8, u8); 4],
+}
+
+impl SpindaSpots {
+    pub fn new(spots: [(u8, u8); 4]) -> Self {
+        SpindaSpots { spots }
+    }
+}
@@ -0,0 +1,5 @@
+/// Unown forms, in the order produced by the letter calculation on the PID
+pub static UNOWN_LETTERS: [char; 28] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '!', '?',
+];
@@ -0,0 +1,5 @@
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WurmpleEvolution {
+    Silcoon,
+    Cascoon,
+}
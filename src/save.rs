@@ -0,0 +1,310 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::pk3::{read_u16, read_u32, Pk3, PK3_BOX_SIZE, PK3_PARTY_SIZE};
+use crate::pokemon::Pokemon;
+use crate::Profile;
+
+const SAVE_SIZE: usize = 0x20000;
+const SECTION_SIZE: usize = 0x1000;
+const SECTIONS_PER_SLOT: usize = 14;
+const SECTION_SIGNATURE: u32 = 0x08012025;
+
+/// The number of bytes of each section covered by its checksum, indexed by section id
+const SECTION_DATA_SIZES: [usize; SECTIONS_PER_SLOT] = [
+    3884, 3968, 3968, 3968, 3848, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 2000,
+];
+
+const BOX_POKEMON_COUNT: usize = 14 * 30;
+
+/// The games a save file can belong to. Ruby and Sapphire share a save layout, so they cannot be told apart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SaveGame {
+    RubySapphire,
+    FireRedLeafGreen,
+    Emerald,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    /// The save is not the 128 KB of a Gen 3 flash save
+    InvalidSize(usize),
+    /// Neither save slot holds a full set of sections with valid checksums
+    NoValidSlot,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "could not read save: {}", e),
+            SaveError::InvalidSize(size) => {
+                write!(f, "expected a {} byte save, got {} bytes", SAVE_SIZE, size)
+            }
+            SaveError::NoValidSlot => write!(f, "no save slot has valid sections"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(e: io::Error) -> Self {
+        SaveError::Io(e)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlayTime {
+    pub hours: u16,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+/// The trainer and pokemon held in a Gen 3 save file
+pub struct SaveFile {
+    pub game: SaveGame,
+    pub trainer_name: String,
+    pub tid: u16,
+    pub sid: u16,
+    pub play_time: PlayTime,
+    pub party: Vec<Pokemon>,
+    pub boxes: Vec<Pokemon>,
+}
+
+impl SaveFile {
+    /// Reads and parses the save file at the given path
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SaveError> {
+        let bytes = fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses a save from its raw bytes.
+    ///
+    /// A save holds two slots of 14 sections each, and the game alternates between them every time it saves.
+    /// The slot with the highest save index whose sections all pass their checksums is the one read.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SaveError> {
+        if bytes.len() != SAVE_SIZE {
+            return Err(SaveError::InvalidSize(bytes.len()));
+        }
+
+        let sections = (0..2)
+            .filter_map(|slot| read_slot(&bytes[slot * SECTIONS_PER_SLOT * SECTION_SIZE..]))
+            .max_by_key(|(save_index, _)| *save_index)
+            .map(|(_, sections)| sections)
+            .ok_or(SaveError::NoValidSlot)?;
+
+        let trainer = sections[0];
+        let game = match read_u32(trainer, 0xAC) {
+            0 => SaveGame::RubySapphire,
+            1 => SaveGame::FireRedLeafGreen,
+            _ => SaveGame::Emerald,
+        };
+
+        let party_offset = match game {
+            SaveGame::FireRedLeafGreen => 0x34,
+            SaveGame::RubySapphire | SaveGame::Emerald => 0x234,
+        };
+        let party_size = (read_u32(sections[1], party_offset) as usize).min(6);
+        let party = (0..party_size)
+            .filter_map(|i| {
+                let start = party_offset + 4 + i * PK3_PARTY_SIZE;
+                decode_pokemon(&sections[1][start..start + PK3_PARTY_SIZE])
+            })
+            .collect();
+
+        // the PC spans sections 5 through 13, starting with the index of the current box
+        let pc: Vec<u8> = (5..SECTIONS_PER_SLOT)
+            .flat_map(|id| sections[id][..SECTION_DATA_SIZES[id]].iter().copied())
+            .collect();
+        let boxes = (0..BOX_POKEMON_COUNT)
+            .filter_map(|i| {
+                let start = 4 + i * PK3_BOX_SIZE;
+                decode_pokemon(&pc[start..start + PK3_BOX_SIZE])
+            })
+            .collect();
+
+        Ok(SaveFile {
+            game,
+            trainer_name: decode_string(&trainer[0x00..0x07]),
+            tid: read_u16(trainer, 0x0A),
+            sid: read_u16(trainer, 0x0C),
+            play_time: PlayTime {
+                hours: read_u16(trainer, 0x0E),
+                minutes: trainer[0x10],
+                seconds: trainer[0x11],
+            },
+            party,
+            boxes,
+        })
+    }
+
+    /// Builds a profile from the trainer's ids, ready to be used by a `Filter`
+    pub fn profile(&self) -> Profile {
+        Profile::new(self.tid, self.sid)
+    }
+}
+
+/// Reads the sections of a save slot, ordered by section id, along with the slot's save index.
+/// Returns None if any section is missing or fails its checksum.
+fn read_slot(slot: &[u8]) -> Option<(u32, [&[u8]; SECTIONS_PER_SLOT])> {
+    let mut sections: [&[u8]; SECTIONS_PER_SLOT] = [&[]; SECTIONS_PER_SLOT];
+    let mut save_index = 0;
+
+    for i in 0..SECTIONS_PER_SLOT {
+        let section = &slot[i * SECTION_SIZE..(i + 1) * SECTION_SIZE];
+        let id = read_u16(section, 0xFF4) as usize;
+        if id >= SECTIONS_PER_SLOT || read_u32(section, 0xFF8) != SECTION_SIGNATURE {
+            return None;
+        }
+        if read_u16(section, 0xFF6) != section_checksum(&section[..SECTION_DATA_SIZES[id]]) {
+            return None;
+        }
+        save_index = read_u32(section, 0xFFC);
+        sections[id] = section;
+    }
+
+    if sections.iter().any(|section| section.is_empty()) {
+        return None;
+    }
+    Some((save_index, sections))
+}
+
+/// Sections are checksummed by adding up their data as 32-bit words, then folding the two halves of the sum together
+fn section_checksum(data: &[u8]) -> u16 {
+    let sum = data
+        .chunks(4)
+        .fold(0u32, |sum, word| sum.wrapping_add(read_u32(word, 0)));
+    ((sum >> 16) as u16).wrapping_add(sum as u16)
+}
+
+/// Empty slots and pokemon which fail their checksum are skipped
+fn decode_pokemon(data: &[u8]) -> Option<Pokemon> {
+    Pk3::from_bytes(data).ok().map(|pk3| pk3.pokemon)
+}
+
+/// Decodes a string in the Gen 3 western character encoding, stopping at the 0xFF terminator
+fn decode_string(data: &[u8]) -> String {
+    data.iter()
+        .take_while(|byte| **byte != 0xFF)
+        .map(|byte| match byte {
+            0x00 => ' ',
+            0xA1..=0xAA => (b'0' + (byte - 0xA1)) as char,
+            0xAB => '!',
+            0xAC => '?',
+            0xAD => '.',
+            0xAE => '-',
+            0xBB..=0xD4 => (b'A' + (byte - 0xBB)) as char,
+            0xD5..=0xEE => (b'a' + (byte - 0xD5)) as char,
+            _ => '?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::IndividualValues;
+
+    /// Builds a save with both slots written, the second slot being the most recent
+    fn build_save(party_pokemon: &[u8]) -> Vec<u8> {
+        let mut save = vec![0u8; SAVE_SIZE];
+
+        for slot in 0..2 {
+            for id in 0..SECTIONS_PER_SLOT {
+                // rotate the sections so they are not stored in id order
+                let position = (id + 3) % SECTIONS_PER_SLOT;
+                let start = (slot * SECTIONS_PER_SLOT + position) * SECTION_SIZE;
+                let section = &mut save[start..start + SECTION_SIZE];
+
+                if id == 0 {
+                    // "RED" followed by the terminator
+                    section[..4].copy_from_slice(&[0xCC, 0xBF, 0xBE, 0xFF]);
+                    section[0x0A..0x0E].copy_from_slice(&[0x75, 0x27, 0x39, 0x30]);
+                    section[0x0E..0x12].copy_from_slice(&[12, 0, 34, 56]);
+                    section[0xAC] = 1;
+                }
+                if id == 1 && slot == 1 {
+                    section[0x34] = 1;
+                    section[0x38..0x38 + PK3_BOX_SIZE].copy_from_slice(party_pokemon);
+                }
+
+                let checksum = section_checksum(&section[..SECTION_DATA_SIZES[id]]);
+                section[0xFF4..0xFF6].copy_from_slice(&(id as u16).to_le_bytes());
+                section[0xFF6..0xFF8].copy_from_slice(&checksum.to_le_bytes());
+                section[0xFF8..0xFFC].copy_from_slice(&SECTION_SIGNATURE.to_le_bytes());
+                section[0xFFC..0x1000].copy_from_slice(&(slot as u32 + 7).to_le_bytes());
+            }
+        }
+
+        save
+    }
+
+    #[test]
+    fn test_parse_save() -> Result<(), String> {
+        let ivs = IndividualValues::new(31, 30, 29, 28, 27, 26);
+        let pokemon = Pokemon::new(0xB58F0B2Au32, ivs);
+        let party_pokemon = Pk3::new(pokemon, &Profile::new(10101, 12345), 1).to_bytes();
+        let save = SaveFile::from_bytes(&build_save(&party_pokemon)).unwrap();
+
+        assert_eq!(save.game, SaveGame::FireRedLeafGreen);
+        assert_eq!(save.trainer_name, "RED");
+        assert_eq!((save.tid, save.sid), (10101, 12345));
+        assert_eq!(
+            save.play_time,
+            PlayTime {
+                hours: 12,
+                minutes: 34,
+                seconds: 56
+            }
+        );
+        assert_eq!(save.party.len(), 1);
+        assert_eq!(save.party[0].pid, 0xB58F0B2Au32);
+        assert_eq!(
+            save.party[0].ivs,
+            IndividualValues::new(31, 30, 29, 28, 27, 26)
+        );
+        assert!(save.boxes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_save_known_pokemon() -> Result<(), String> {
+        // PID 9 stores its substructures in the order AEMG, which is not its own inverse
+        let party_pokemon: [u8; PK3_BOX_SIZE] = [
+            0x09, 0x00, 0x00, 0x00, 0x75, 0x27, 0x39, 0x30, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0xB6, 0x2D, 0x00, 0x00, 0x7C, 0x27, 0x39, 0x30, 0x7C, 0x27, 0x39, 0x30, 0x7C, 0x27,
+            0x39, 0x30, 0x7C, 0x27, 0x39, 0x30, 0x7C, 0x27, 0x39, 0x30, 0x7C, 0x27, 0x39, 0x30,
+            0x7C, 0x27, 0x39, 0x30, 0xA3, 0x50, 0x87, 0x85, 0x7C, 0x27, 0x39, 0x30, 0x65, 0x27,
+            0x39, 0x30, 0x7C, 0x27, 0x39, 0x30, 0x7C, 0x27, 0x39, 0x30,
+        ];
+        let save = SaveFile::from_bytes(&build_save(&party_pokemon)).unwrap();
+
+        assert_eq!(save.party.len(), 1);
+        assert_eq!(save.party[0].pid, 9);
+        assert_eq!(
+            save.party[0].ivs,
+            IndividualValues::new(31, 30, 29, 27, 26, 28)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_corrupt_slot_falls_back() -> Result<(), String> {
+        let pokemon = Pokemon::new(1, IndividualValues::default());
+        let mut save = build_save(&Pk3::new(pokemon, &Profile::new(1, 2), 1).to_bytes());
+        // corrupting the newest slot leaves the older slot, which has an empty party
+        save[SECTIONS_PER_SLOT * SECTION_SIZE] ^= 0xFF;
+        let save = SaveFile::from_bytes(&save).unwrap();
+        assert!(save.party.is_empty());
+
+        assert!(matches!(
+            SaveFile::from_bytes(&[0u8; 16]),
+            Err(SaveError::InvalidSize(16))
+        ));
+        Ok(())
+    }
+}
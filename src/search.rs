@@ -1,49 +1,404 @@
+use std::collections::VecDeque;
 use std::fmt;
 
-use crate::generators::{Game, Generator, LinearCongruential, Method};
-use crate::pokemon::Pokemon;
-use crate::Filter;
+use crate::events::SeedRange;
+use crate::generators::{
+    gen4_egg_pid, FrameAdvancement, Game, Generated, GenerationMethod, LcrngBatch,
+    LinearCongruential, MersenneTwister, Method, Rng, SeedableRng, BATCH_LANES,
+};
+use crate::pokemon::{IndividualValues, Pokemon};
+use crate::{Filter, Profile};
 
-pub struct Searcher {
-    method: Method,
-    rng: Box<dyn Generator>,
+/// Searches the frames of an rng for the pokemon a generation method produces on each of them.
+/// Gen 3 and 4 games use the lcrng, but the searcher runs over any rng, such as the mersenne twisters.
+pub struct Searcher<R: Rng = LinearCongruential> {
+    method: Box<dyn GenerationMethod>,
+    rng: FrameRng<R>,
+    /// Generates a frame by saving and restoring the seed, for rngs whose state is their seed. Other rngs replay their buffered outputs.
+    restore: Option<RestoreFrame<R>>,
     frame_limit: usize,
+    advancement: FrameAdvancement,
 }
 
 impl Searcher {
-    pub fn new(game: Game, method: Method, frame_limit: usize) -> Self {
+    /// Constructs a searcher for one of masuda's `Method`s, or for any other implementation of `GenerationMethod`
+    pub fn new<M: GenerationMethod + 'static>(game: Game, method: M, frame_limit: usize) -> Self {
         let rng = match game {
-            Game::FireRed | Game::LeafGreen => Box::new(LinearCongruential::new(0)),
-            Game::Emerald => Box::new(LinearCongruential::new(0)),
-            Game::Ruby | Game::Sapphire => Box::new(LinearCongruential::new(0x5A0)),
+            Game::FireRed | Game::LeafGreen => LinearCongruential::new(0),
+            Game::Emerald => LinearCongruential::new(0),
+            Game::Ruby | Game::Sapphire => LinearCongruential::new(0x5A0),
+            // the seed of a Gen 4 game depends on when it was loaded, see `seed::gen4_seed`
+            Game::Diamond | Game::Pearl | Game::Platinum | Game::HeartGold | Game::SoulSilver => {
+                LinearCongruential::new(0)
+            }
         };
 
+        Self::with_rng(method, rng, frame_limit)
+    }
+
+    /// Constructs a searcher starting from the given initial seed rather than the game's default, as needed for games seeded from the clock
+    pub fn with_seed<M: GenerationMethod + 'static>(
+        method: M,
+        initial_seed: u32,
+        frame_limit: usize,
+    ) -> Self {
+        Self::with_rng(method, LinearCongruential::new(initial_seed), frame_limit)
+    }
+}
+
+impl<R: SeedableRng> Searcher<R> {
+    /// Constructs a searcher over an rng whose state is its seed, starting from its current seed.
+    /// The seed is restored after each frame, so no outputs are buffered.
+    pub fn with_rng<M: GenerationMethod + 'static>(method: M, rng: R, frame_limit: usize) -> Self {
         Self {
-            method,
-            rng,
+            restore: Some(restore_frame::<R>),
+            ..Searcher::with_replay(method, rng, frame_limit)
+        }
+    }
+}
+
+impl<R: Rng> Searcher<R> {
+    /// Constructs a searcher over any rng, starting from its current state.
+    /// The outputs drawn by each frame are buffered and replayed for the following frames, as rngs such as the mersenne twisters cannot restore their state cheaply.
+    pub fn with_replay<M: GenerationMethod + 'static>(
+        method: M,
+        rng: R,
+        frame_limit: usize,
+    ) -> Self {
+        Self {
+            method: Box::new(method),
+            rng: FrameRng::new(rng),
+            restore: None,
             frame_limit,
+            advancement: FrameAdvancement::new(),
         }
     }
 
-    fn advance(&mut self) -> Pokemon {
-        match self.method {
-            Method::One => self.rng.method_1(),
-            Method::Two => self.rng.method_2(),
-            Method::Four => self.rng.method_4(),
+    /// Sets how in-game frames map to RNG advances, so that the frames of the results line up with the frames of a timer.
+    /// By default the RNG advances once per frame.
+    pub fn with_advancement(mut self, advancement: FrameAdvancement) -> Self {
+        self.advancement = advancement;
+        self
+    }
+
+    /// Generates the pokemon of the current frame and steps to the next one
+    fn advance(&mut self) -> Option<Generated> {
+        if let Some(restore) = self.restore {
+            return restore(&mut self.rng.rng, self.method.as_ref());
         }
+        let generated = self.method.generate(&mut self.rng.frame());
+        self.rng.skip(1);
+        generated
     }
 
     pub fn search(&mut self, filter: Option<Filter>) -> Vec<SearchResult> {
         let mut results = Vec::new();
+        let mut advances = 0;
 
         for frame in 0..self.frame_limit {
-            let p = self.advance();
+            let target = self.advancement.advances(frame);
+            if target > advances {
+                self.rng.skip(target - advances);
+                advances = target;
+            }
+            let generated = self.advance();
+            advances += 1;
+            let Some(generated) = generated else {
+                continue;
+            };
+            if let Some(filter) = &filter {
+                if !filter.matches(&generated.pokemon) {
+                    continue;
+                }
+            }
+            results.push(SearchResult {
+                pokemon: generated.pokemon,
+                frame,
+                advances: generated.advances,
+                slot: generated.slot,
+                level: generated.level,
+            });
+        }
+
+        results
+    }
+}
+
+/// Generates a frame of a searcher over an rng whose state is its seed
+type RestoreFrame<R> = fn(&mut R, &dyn GenerationMethod) -> Option<Generated>;
+
+/// Generates the pokemon of the current frame, then restores the seed and steps to the next frame
+fn restore_frame<R: SeedableRng>(rng: &mut R, method: &dyn GenerationMethod) -> Option<Generated> {
+    let seed = rng.seed();
+    let generated = method.generate(rng);
+    rng.reseed(seed);
+    rng.next_u32();
+    generated
+}
+
+/// Buffers the outputs of an rng, so that each frame can draw as many numbers as it needs while the next frame still starts a single call later.
+/// Replaying the outputs keeps large states such as the mersenne twisters from being copied on every frame.
+struct FrameRng<R: Rng> {
+    rng: R,
+    buffered: VecDeque<u32>,
+}
+
+impl<R: Rng> FrameRng<R> {
+    fn new(rng: R) -> Self {
+        Self {
+            rng,
+            buffered: VecDeque::new(),
+        }
+    }
+
+    /// An rng drawing the outputs of the current frame, starting from its first call
+    fn frame(&mut self) -> Frame<'_, R> {
+        Frame {
+            replay: self,
+            position: 0,
+        }
+    }
+
+    /// Moves the start of the current frame on by the given number of calls
+    fn skip(&mut self, advances: usize) {
+        let buffered = advances.min(self.buffered.len());
+        self.buffered.drain(..buffered);
+        self.rng.advance(advances - buffered);
+    }
+}
+
+struct Frame<'a, R: Rng> {
+    replay: &'a mut FrameRng<R>,
+    position: usize,
+}
+
+impl<R: Rng> Rng for Frame<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        if self.position == self.replay.buffered.len() {
+            let n = self.replay.rng.next_u32();
+            self.replay.buffered.push_back(n);
+        }
+        self.position += 1;
+        self.replay.buffered[self.position - 1]
+    }
+}
+
+/// Searches every 32-bit seed for the pokemon generated at frame 0 of it, as needed when the seed itself is chosen or unknown,
+/// such as for reverse verification or picking a Gen 4 seed.
+/// The seeds are those of the rng the method draws from, the XDRNG for Colosseum, XD and Pokemon Channel, see `Method::new_rng`.
+pub struct SeedSearcher {
+    method: Method,
+    iv_calls: IvCalls,
+}
+
+/// The rng calls a method draws its IVs from, counting from 1, which decide how the seed space is pruned
+#[derive(Copy, Clone)]
+enum IvCalls {
+    /// The two IV half-words, HP, Atk and Def first and Spe, SpA and SpD second, as the Gen 3 and 4 methods draw them
+    HalfWords(usize, usize),
+    /// One call per IV in the order HP, Atk, Def, Spe, SpA, SpD starting from the given one, each IV taken from the top 5 bits
+    PerStat(usize),
+}
+
+#[derive(Debug)]
+pub enum SeedSearchError {
+    /// The method rerolls its PID until it fits, so its IVs are drawn after a varying number of rng calls
+    VaryingIvCalls,
+}
+
+impl fmt::Display for SeedSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeedSearchError::VaryingIvCalls => write!(
+                f,
+                "the method draws its IVs after a varying number of rng calls"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SeedSearchError {}
+
+impl SeedSearcher {
+    /// Constructs a searcher for the given method.
+    /// Methods J and K and the Tanoby Chambers reroll their PID until it fits, so their seeds cannot be pruned by IVs and are rejected.
+    pub fn new(method: Method) -> Result<Self, SeedSearchError> {
+        let iv_calls = match method {
+            Method::One | Method::OneReverse | Method::Event(..) => IvCalls::HalfWords(3, 4),
+            Method::Two | Method::Three => IvCalls::HalfWords(4, 5),
+            Method::Four => IvCalls::HalfWords(3, 5),
+            // the PID is computed from the trainer rather than drawn from the LCRNG
+            Method::Pokewalker(_) => IvCalls::HalfWords(1, 2),
+            Method::XdColo => IvCalls::HalfWords(1, 2),
+            Method::Channel => IvCalls::PerStat(7),
+            Method::TanobyH1(_) | Method::J(..) | Method::K(..) => {
+                return Err(SeedSearchError::VaryingIvCalls)
+            }
+        };
+
+        Ok(Self { method, iv_calls })
+    }
+
+    /// Searches for the seeds whose frame 0 matches the filter.
+    ///
+    /// For the methods drawing IV half-words, the filter is decomposed into the half-words it allows, and only the states drawing an allowed first IV half-word are visited,
+    /// so a filter constraining HP, Atk and Def prunes most of the seed space. The second IV half-word is checked for a batch of states at once, see `LcrngBatch`,
+    /// before the matching seeds are walked back and generated.
+    /// Pokemon Channel draws one call per IV, so only the states drawing an allowed HP followed by an allowed Atk are visited.
+    /// Restricted event distributions only visit the 16-bit seeds they can start from.
+    pub fn search(&self, filter: &Filter) -> Vec<SeedResult> {
+        let mut results = Vec::new();
+        let mut check = |seed: u32| {
+            let generated = self.method.generate(&mut self.method.new_rng(seed));
+            let Some(p) = generated.map(|generated| generated.pokemon) else {
+                return;
+            };
+            if filter.matches(&p) {
+                results.push(SeedResult { seed, pokemon: p });
+            }
+        };
+
+        if let Method::Event(_, SeedRange::Restricted) = self.method {
+            (0..=0xFFFF).for_each(check);
+            return results;
+        }
+
+        let mut rng = self.method.new_rng(0);
+        match self.iv_calls {
+            IvCalls::HalfWords(first_iv_call, second_iv_call) => {
+                let [first_half_words, second_half_words] = filter.iv_half_words();
+                let iv_distance = (second_iv_call - first_iv_call) as u64;
+                for half_word in (0..0x8000u32).filter(|h| first_half_words[*h as usize]) {
+                    // the unused top bit of the half-word can take either value
+                    for high in [half_word << 16, (half_word | 0x8000) << 16] {
+                        let mut batch = LcrngBatch::from_seeds_of(&rng, high);
+                        for _ in 0..0x10000 / BATCH_LANES {
+                            let second_rands = batch.rands_ahead(iv_distance);
+                            for (seed, rand) in batch.seeds().iter().zip(second_rands.iter()) {
+                                if !second_half_words[(rand & 0x7FFF) as usize] {
+                                    continue;
+                                }
+
+                                rng.set_seed(*seed);
+                                for _ in 0..first_iv_call {
+                                    rng.prev_u32();
+                                }
+                                check(rng.seed());
+                            }
+                            batch.next_batch();
+                        }
+                    }
+                }
+            }
+            IvCalls::PerStat(first_iv_call) => {
+                let allowed = filter.allowed_ivs();
+                let constants = rng.constants();
+                let allowed_ivs =
+                    |stat: usize| (0..32u8).filter(move |iv| allowed[stat][*iv as usize]);
+                for hp in allowed_ivs(0) {
+                    for atk in allowed_ivs(1) {
+                        for_each_iv_pair_state(constants, hp, atk, |state| {
+                            rng.set_seed(state);
+                            rng.step();
+                            if !(2..6).all(|stat| allowed[stat][(rng.next_u16() >> 11) as usize]) {
+                                return;
+                            }
+
+                            rng.set_seed(state);
+                            for _ in 0..first_iv_call {
+                                rng.prev_u32();
+                            }
+                            check(rng.seed());
+                        });
+                    }
+                }
+            }
+        }
+
+        results
+    }
+}
+
+/// Visits every state drawing the first IV from its top 5 bits whose following state draws the second IV.
+///
+/// Consecutive states with the same top 5 bits have following states a multiplier apart, so rather than visiting each of them,
+/// the walk jumps straight to the next state whose following state lands on the second IV.
+fn for_each_iv_pair_state(
+    (multiplier, increment): (u32, u32),
+    first: u8,
+    second: u8,
+    mut visit: impl FnMut(u32),
+) {
+    let start = (first as u32) << 27;
+    let target = (second as u32) << 27;
+    let base = start.wrapping_mul(multiplier).wrapping_add(increment);
+
+    let mut offset = 0u64;
+    while offset < 1 << 27 {
+        let next = base.wrapping_add(multiplier.wrapping_mul(offset as u32));
+        if next >> 27 == second as u32 {
+            visit(start | offset as u32);
+            offset += 1;
+        } else {
+            let distance = target.wrapping_sub(next) as u64;
+            offset += distance.div_ceil(multiplier as u64);
+        }
+    }
+}
+
+pub struct SeedResult {
+    pub seed: u32,
+    pub pokemon: Pokemon,
+}
+
+impl fmt::Debug for SeedResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "seed {:08X}: {:?}", self.seed, self.pokemon)
+    }
+}
+
+/// Searches the egg PIDs of a Gen 4 game, which are drawn from the mersenne twister rather than the LCRNG.
+/// Each frame is a single advance of the twister.
+pub struct EggSearcher {
+    mt: MersenneTwister,
+    masuda_method: bool,
+    frame_limit: usize,
+}
+
+impl EggSearcher {
+    pub fn new(initial_seed: u32, masuda_method: bool, frame_limit: usize) -> Self {
+        Self {
+            mt: MersenneTwister::new(initial_seed),
+            masuda_method,
+            frame_limit,
+        }
+    }
+
+    /// Searches for egg PIDs for the given trainer, whose ids decide the Masuda Method rerolls.
+    /// The IVs of an egg are only decided when it is picked up, so the results hold no IVs and stat filters should be left unset.
+    pub fn search(&mut self, profile: &Profile, filter: Option<Filter>) -> Vec<SearchResult> {
+        let mut results = Vec::new();
+        let masuda_method = if self.masuda_method {
+            Some(profile)
+        } else {
+            None
+        };
+
+        for frame in 0..self.frame_limit {
+            let pid = gen4_egg_pid(&mut self.mt, masuda_method);
+            let p = Pokemon::new(pid, IndividualValues::default());
             if let Some(filter) = &filter {
                 if !filter.matches(&p) {
                     continue;
                 }
             }
-            results.push(SearchResult { pokemon: p, frame });
+            results.push(SearchResult {
+                pokemon: p,
+                frame,
+                advances: 1,
+                slot: None,
+                level: None,
+            });
         }
 
         results
@@ -53,6 +408,12 @@ impl Searcher {
 pub struct SearchResult {
     pub pokemon: Pokemon,
     pub frame: usize,
+    /// The number of RNG calls made to generate the pokemon
+    pub advances: usize,
+    /// The encounter slot, for methods which roll one
+    pub slot: Option<u8>,
+    /// The level, for methods which roll one
+    pub level: Option<u8>,
 }
 
 impl fmt::Debug for SearchResult {
@@ -60,3 +421,196 @@ impl fmt::Debug for SearchResult {
         write!(f, "frame {}: {:?}", self.frame, self.pokemon,)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{EncounterTrigger, TanobyChamber};
+
+    #[test]
+    fn test_search_with_advancement() -> Result<(), String> {
+        let every_frame = Searcher::with_seed(Method::One, 0, 201).search(None);
+
+        let battle = Searcher::with_seed(Method::One, 0, 100)
+            .with_advancement(FrameAdvancement::emerald_battle())
+            .search(None);
+        for result in battle.iter() {
+            assert_eq!(
+                result.pokemon.pid,
+                every_frame[result.frame * 2].pokemon.pid
+            );
+        }
+
+        let walking = Searcher::with_seed(Method::One, 0, 100)
+            .with_advancement(FrameAdvancement::new().with_trigger(EncounterTrigger::Walking))
+            .search(None);
+        assert_eq!(walking[0].pokemon.pid, every_frame[1].pokemon.pid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_generation_method() -> Result<(), String> {
+        /// a pokemon whose PID and IVs are both built from a single call
+        struct SingleCall;
+
+        impl GenerationMethod for SingleCall {
+            fn generate(&self, rng: &mut dyn Rng) -> Option<Generated> {
+                let n = rng.next_u16();
+                let pokemon = Pokemon::new(n as u32, IndividualValues::new_from_numbers(n, n));
+                Some(Generated::new(pokemon, 1).with_level(5))
+            }
+        }
+
+        let results = Searcher::with_seed(SingleCall, 0, 3).search(None);
+        let mut rng = LinearCongruential::new(0);
+        for result in results {
+            assert_eq!(result.pokemon.pid, rng.next_u16() as u32);
+            assert_eq!((result.advances, result.level), (1, Some(5)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_metadata() -> Result<(), String> {
+        let method_1 = Searcher::with_seed(Method::One, 0, 1).search(None);
+        assert_eq!((method_1[0].advances, method_1[0].slot), (4, None));
+
+        let chamber = TanobyChamber::Monean;
+        let tanoby = Searcher::with_seed(Method::TanobyH1(chamber), 0, 1).search(None);
+        let slot = chamber.slot_for((LinearCongruential::new(0).next_u32() >> 16) as u16);
+        assert_eq!(tanoby[0].slot, Some(slot));
+        // slot, level and nature calls, the PID calls of each roll, then the IVs
+        assert!(tanoby[0].advances >= 7 && !tanoby[0].advances.is_multiple_of(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_methods() -> Result<(), String> {
+        use crate::events::{Event, PidOrder};
+
+        let seed = 0x1A56B091;
+        let reverse = Searcher::with_seed(Method::OneReverse, seed, 1).search(None);
+        assert_eq!(reverse[0].pokemon.pid, Event::Wishmaker.generate(seed).pid);
+
+        let three = Searcher::with_seed(Method::Three, seed, 1).search(None);
+        let mut rng = LinearCongruential::new(seed);
+        let low = rng.next_u16() as u32;
+        rng.step();
+        let high = rng.next_u16() as u32;
+        assert_eq!(three[0].pokemon.pid, (high << 16) | low);
+
+        // a restricted distribution is only found from its 16-bit seed
+        let event = Event::Wishmaker.generate(0x1234);
+        let filter = Filter::new(&Profile::new(0, 0)).with_nature(event.get_nature());
+        let method = Method::Event(PidOrder::Reverse, SeedRange::Restricted);
+        let results = SeedSearcher::new(method)
+            .map_err(|e| e.to_string())?
+            .search(&filter);
+        assert!(results.iter().any(|result| result.seed == 0x1234));
+        assert!(results.iter().all(|result| result.seed <= 0xFFFF));
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_search() -> Result<(), String> {
+        use crate::filter::{StatComparison, StatFilter};
+        use crate::generators::Pokewalker;
+        use crate::pokemon::Nature;
+
+        let profile = Profile::new(0, 0);
+        for method in [
+            Method::One,
+            Method::Two,
+            Method::Three,
+            Method::Four,
+            Method::OneReverse,
+            Method::Pokewalker(Pokewalker::new(0, 0, Nature::Adamant, None)),
+            Method::XdColo,
+            Method::Channel,
+        ] {
+            let seed = 0xCAFEBABE;
+            let target = method
+                .generate(&mut method.new_rng(seed))
+                .ok_or("no encounter")?
+                .pokemon;
+            let ivs = &target.ivs;
+            let filter = Filter::new(&profile)
+                .with_nature(target.get_nature())
+                .with_stat(StatFilter::HP(StatComparison::EqualTo(ivs.hp)))
+                .with_stat(StatFilter::Attack(StatComparison::EqualTo(ivs.atk)))
+                .with_stat(StatFilter::Defense(StatComparison::EqualTo(ivs.def)))
+                .with_stat(StatFilter::SpecialAttack(StatComparison::EqualTo(ivs.spa)))
+                .with_stat(StatFilter::SpecialDefense(StatComparison::EqualTo(ivs.spd)))
+                .with_stat(StatFilter::Speed(StatComparison::EqualTo(ivs.spe)));
+
+            let results = SeedSearcher::new(method)
+                .map_err(|e| e.to_string())?
+                .search(&filter);
+            assert!(results
+                .iter()
+                .any(|result| result.seed == seed && result.pokemon.pid == target.pid));
+            assert!(results.iter().all(|result| filter.matches(&result.pokemon)));
+        }
+        Ok(())
+    }
+    #[test]
+    fn test_seed_search_gamecube() -> Result<(), String> {
+        use crate::filter::{StatComparison, StatFilter};
+        use crate::generators::Channel;
+        use crate::legality::{find_origins, Origin};
+
+        // the shadow pokemon found from the XDRNG are legal Colosseum and XD pokemon
+        let filter = Filter::new(&Profile::new(0, 0))
+            .with_stat(StatFilter::HP(StatComparison::EqualTo(31)))
+            .with_stat(StatFilter::Attack(StatComparison::EqualTo(31)))
+            .with_stat(StatFilter::Defense(StatComparison::EqualTo(31)));
+        let results = SeedSearcher::new(Method::XdColo)
+            .map_err(|e| e.to_string())?
+            .search(&filter);
+        assert!(!results.is_empty());
+        for result in results.iter().take(4) {
+            let origins = find_origins(&result.pokemon, None, None);
+            assert!(origins
+                .iter()
+                .any(|origin| origin.origin == Origin::ColosseumXD && origin.seed == result.seed));
+        }
+
+        let jirachi = Channel::new(0x12345678).generate().pokemon;
+        let ivs = &jirachi.ivs;
+        let filter = Filter::new(&Profile::new(0, 0))
+            .with_stat(StatFilter::HP(StatComparison::EqualTo(ivs.hp)))
+            .with_stat(StatFilter::Attack(StatComparison::EqualTo(ivs.atk)))
+            .with_stat(StatFilter::Defense(StatComparison::EqualTo(ivs.def)))
+            .with_stat(StatFilter::SpecialAttack(StatComparison::EqualTo(ivs.spa)))
+            .with_stat(StatFilter::SpecialDefense(StatComparison::EqualTo(ivs.spd)))
+            .with_stat(StatFilter::Speed(StatComparison::EqualTo(ivs.spe)));
+        let results = SeedSearcher::new(Method::Channel)
+            .map_err(|e| e.to_string())?
+            .search(&filter);
+        assert!(results.iter().any(|result| result.seed == 0x12345678));
+        let matching: Vec<u32> = results
+            .iter()
+            .filter(|result| result.pokemon.pid == jirachi.pid)
+            .map(|result| result.seed)
+            .collect();
+        assert_eq!(matching, Channel::seeds_for(&jirachi));
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_search_rejects_varying_iv_calls() -> Result<(), String> {
+        use crate::generators::{Lead, TanobyChamber, WildEncounter};
+
+        for method in [
+            Method::J(Lead::None, WildEncounter::Grass),
+            Method::K(Lead::None, WildEncounter::Grass),
+            Method::TanobyH1(TanobyChamber::Liptoo),
+        ] {
+            assert!(matches!(
+                SeedSearcher::new(method),
+                Err(SeedSearchError::VaryingIvCalls)
+            ));
+        }
+        Ok(())
+    }
+}
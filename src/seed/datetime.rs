@@ -0,0 +1,158 @@
+use std::fmt;
+
+/// A date and time as set on the console clock
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Number of days in the given month of the given year, accounting for leap years
+    pub fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            2 if year.is_multiple_of(4)
+                && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Day of the week, with 0 being Sunday
+    pub fn day_of_week(&self) -> u8 {
+        const MONTH_OFFSETS: [u16; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let days = year + year / 4 - year / 100
+            + year / 400
+            + MONTH_OFFSETS[self.month as usize - 1]
+            + self.day as u16;
+        (days % 7) as u8
+    }
+
+    /// The date and time one second later, rolling over into the next minute, hour, day, month and year as needed
+    pub fn next_second(&self) -> Self {
+        let mut next = *self;
+        next.second += 1;
+        if next.second == 60 {
+            next.second = 0;
+            next.minute += 1;
+        }
+        if next.minute == 60 {
+            next.minute = 0;
+            next.hour += 1;
+        }
+        if next.hour == 24 {
+            next.hour = 0;
+            next.day += 1;
+        }
+        if next.day > Self::days_in_month(next.year, next.month) {
+            next.day = 1;
+            next.month += 1;
+        }
+        if next.month == 13 {
+            next.month = 1;
+            next.year += 1;
+        }
+        next
+    }
+
+    /// The date and time one second earlier, rolling back into the previous minute, hour, day, month and year as needed
+    pub fn previous_second(&self) -> Self {
+        let mut previous = *self;
+        if previous.second > 0 {
+            previous.second -= 1;
+            return previous;
+        }
+        previous.second = 59;
+        if previous.minute > 0 {
+            previous.minute -= 1;
+            return previous;
+        }
+        previous.minute = 59;
+        if previous.hour > 0 {
+            previous.hour -= 1;
+            return previous;
+        }
+        previous.hour = 23;
+        if previous.day > 1 {
+            previous.day -= 1;
+            return previous;
+        }
+        if previous.month > 1 {
+            previous.month -= 1;
+        } else {
+            previous.month = 12;
+            previous.year -= 1;
+        }
+        previous.day = Self::days_in_month(previous.year, previous.month);
+        previous
+    }
+}
+
+impl fmt::Debug for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_second() -> Result<(), String> {
+        let new_year = DateTime::new(2011, 1, 1, 0, 0, 0);
+        assert!(new_year.previous_second() == DateTime::new(2010, 12, 31, 23, 59, 59));
+        assert!(new_year.previous_second().next_second() == new_year);
+        assert!(
+            DateTime::new(2012, 3, 1, 0, 0, 0).previous_second()
+                == DateTime::new(2012, 2, 29, 23, 59, 59)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_of_week() -> Result<(), String> {
+        assert_eq!(DateTime::new(2011, 3, 6, 0, 0, 0).day_of_week(), 0);
+        assert_eq!(DateTime::new(2000, 1, 1, 0, 0, 0).day_of_week(), 6);
+        assert_eq!(DateTime::new(2024, 2, 29, 0, 0, 0).day_of_week(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_second_rolls_over() -> Result<(), String> {
+        let datetime = DateTime::new(2011, 12, 31, 23, 59, 59);
+        assert!(datetime.next_second() == DateTime::new(2012, 1, 1, 0, 0, 0));
+
+        let datetime = DateTime::new(2012, 2, 28, 23, 59, 59);
+        assert!(datetime.next_second() == DateTime::new(2012, 2, 29, 0, 0, 0));
+        Ok(())
+    }
+}
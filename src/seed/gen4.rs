@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::seed::DateTime;
+
+/// A date, time and delay which seeds a Gen 4 game
+pub struct SeedTime {
+    pub datetime: DateTime,
+    pub delay: u32,
+}
+
+impl fmt::Debug for SeedTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} delay {}", self.datetime, self.delay)
+    }
+}
+
+/// Computes the initial seed of Diamond, Pearl, Platinum, HeartGold and SoulSilver.
+///
+/// The seed is built from the console clock at the moment the game is loaded, and the delay, which is the number of frames between boot and continuing the save.
+/// It can be illustrated as
+///
+/// ```mono
+/// AABBCCCC
+/// AA:   (month * day + minute + second) % 256
+/// BB:   hour
+/// CCCC: year - 2000 + delay
+/// ```
+///
+/// The parts are added rather than ORed together, so a delay longer than 16 bits carries into the hour.
+///
+/// ```
+/// # use masuda::seed::{gen4_seed, DateTime};
+/// let datetime = DateTime::new(2009, 1, 1, 0, 0, 0);
+/// assert_eq!(gen4_seed(&datetime, 600), 0x01000261u32);
+/// ```
+pub fn gen4_seed(datetime: &DateTime, delay: u32) -> u32 {
+    let ab = (datetime.month as u32 * datetime.day as u32
+        + datetime.minute as u32
+        + datetime.second as u32)
+        & 0xFF;
+    let cd = (datetime.hour as u32) << 16;
+    let efgh = (datetime.year as u32)
+        .wrapping_sub(2000)
+        .wrapping_add(delay);
+
+    (ab << 24).wrapping_add(cd).wrapping_add(efgh)
+}
+
+/// Finds every date and time in the given year, with a delay no larger than max_delay, which produces the target seed.
+///
+/// The top byte of the seed only depends on the month, day, minute and second, so each combination of those is matched against it,
+/// then the delay is recovered for every hour of the day.
+pub fn gen4_seed_times(target: u32, year: u16, max_delay: u32) -> Vec<SeedTime> {
+    let ab = target >> 24;
+    let mut times = Vec::new();
+
+    for month in 1..=12u8 {
+        for day in 1..=DateTime::days_in_month(year, month) {
+            for minute in 0..60u8 {
+                for second in 0..60u8 {
+                    if (month as u32 * day as u32 + minute as u32 + second as u32) & 0xFF != ab {
+                        continue;
+                    }
+
+                    for hour in 0..24u8 {
+                        let datetime = DateTime::new(year, month, day, hour, minute, second);
+                        let delay = target.wrapping_sub(gen4_seed(&datetime, 0));
+                        if delay <= max_delay {
+                            times.push(SeedTime { datetime, delay });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    times
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen4_seed_times_round_trip() -> Result<(), String> {
+        let datetime = DateTime::new(2010, 7, 15, 13, 42, 9);
+        let seed = gen4_seed(&datetime, 631);
+
+        let times = gen4_seed_times(seed, 2010, 1000);
+        assert!(times
+            .iter()
+            .any(|time| time.datetime == datetime && time.delay == 631));
+        assert!(times
+            .iter()
+            .all(|time| gen4_seed(&time.datetime, time.delay) == seed));
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::generators::{gen5_iv_frames, IvFrame, LinearCongruential64};
+use crate::seed::sha1::sha1_block;
+use crate::seed::DateTime;
+use crate::Filter;
+
+/// Version specific values hashed into the seed of English Black
+pub const NAZO_BLACK_EN: [u32; 5] = [0x02215F10, 0x0221600C, 0x0221600C, 0x02216058, 0x02216058];
+/// Version specific values hashed into the seed of English White
+pub const NAZO_WHITE_EN: [u32; 5] = [0x02215F30, 0x0221602C, 0x0221602C, 0x02216078, 0x02216078];
+
+/// Value the keypad register holds when no key is pressed
+const NO_KEYS: u32 = 0x2FFF;
+
+/// The console and version specific values which, along with the clock, Timer0 and key presses, make up a Gen 5 seed
+pub struct Gen5Profile {
+    /// Values which differ between versions and languages of the game
+    pub nazo: [u32; 5],
+    /// The 48-bit MAC address of the console
+    pub mac: u64,
+    pub vcount: u8,
+    pub gxstat: u32,
+    pub vframe: u8,
+    /// Whether the game runs on a 3DS, which does not set the PM flag on the hour
+    pub is_3ds: bool,
+}
+
+impl Gen5Profile {
+    pub fn new(
+        nazo: [u32; 5],
+        mac: u64,
+        vcount: u8,
+        gxstat: u32,
+        vframe: u8,
+        is_3ds: bool,
+    ) -> Self {
+        Gen5Profile {
+            nazo,
+            mac,
+            vcount,
+            gxstat,
+            vframe,
+            is_3ds,
+        }
+    }
+}
+
+/// A date, time, Timer0 and key combination which seeds a Gen 5 game
+#[derive(Copy, Clone)]
+pub struct Gen5SeedTime {
+    pub datetime: DateTime,
+    pub timer0: u16,
+    /// Pressed keys as a bitmask, A being 0x1 through Y being 0x800, in the order of the DS keypad register
+    pub keys: u16,
+    pub seed: u64,
+}
+
+impl fmt::Debug for Gen5SeedTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} timer0 {:x} keys {:x}: {:016x}",
+            self.datetime, self.timer0, self.keys, self.seed
+        )
+    }
+}
+
+/// Computes the initial seed of Black, White, Black 2 and White 2.
+///
+/// The game hashes a message of 13 words with SHA-1:
+///
+/// ```mono
+/// 0-4:   nazo values of the version
+/// 5:     VCount and Timer0
+/// 6-7:   MAC address, XORed with GxStat and VFrame
+/// 8:     date in BCD, and day of the week
+/// 9:     time in BCD, with 0x40 added to the hour in the afternoon on a DS
+/// 10-11: 0
+/// 12:    keys held
+/// ```
+///
+/// The first two words of the digest form the 64-bit seed, which is advanced once through the 64-bit LCRNG.
+/// Words the game stores little endian are byte swapped, as SHA-1 reads big endian words.
+pub fn gen5_seed(profile: &Gen5Profile, datetime: &DateTime, timer0: u16, keys: u16) -> u64 {
+    let digest = sha1_block(&seed_message(profile, datetime, timer0, keys));
+    let hash = (digest[1].swap_bytes() as u64) << 32 | digest[0].swap_bytes() as u64;
+    LinearCongruential64::new(hash).next_u64()
+}
+
+/// Lays out the message hashed by `gen5_seed` as a padded SHA-1 block
+fn seed_message(profile: &Gen5Profile, datetime: &DateTime, timer0: u16, keys: u16) -> [u32; 16] {
+    let mut block = [0u32; 16];
+    for (word, nazo) in block.iter_mut().zip(profile.nazo.iter()) {
+        *word = nazo.swap_bytes();
+    }
+    block[5] = ((profile.vcount as u32) << 16 | timer0 as u32).swap_bytes();
+    block[6] = (profile.mac & 0xFFFF) as u32;
+    block[7] = (((profile.mac >> 16) as u32) ^ profile.gxstat ^ (profile.vframe as u32) << 24)
+        .swap_bytes();
+
+    block[8] = (bcd(datetime.year % 100) as u32) << 24
+        | (bcd(datetime.month as u16) as u32) << 16
+        | (bcd(datetime.day as u16) as u32) << 8
+        | datetime.day_of_week() as u32;
+    let pm_flag = if datetime.hour >= 12 && !profile.is_3ds {
+        0x40
+    } else {
+        0
+    };
+    block[9] = ((bcd(datetime.hour as u16) + pm_flag) as u32) << 24
+        | (bcd(datetime.minute as u16) as u32) << 16
+        | (bcd(datetime.second as u16) as u32) << 8;
+
+    block[12] = (NO_KEYS ^ keys as u32).swap_bytes();
+    // SHA-1 padding for a 416-bit message
+    block[13] = 0x80000000;
+    block[15] = 0x1A0;
+    block
+}
+
+/// Searches every second between start and end inclusive, each Timer0 value and each key combination,
+/// keeping the combinations whose seed is accepted by the predicate.
+pub fn gen5_seed_times<F: FnMut(u64) -> bool>(
+    profile: &Gen5Profile,
+    start: DateTime,
+    end: DateTime,
+    timer0: RangeInclusive<u16>,
+    keys: &[u16],
+    mut predicate: F,
+) -> Vec<Gen5SeedTime> {
+    let mut times = Vec::new();
+    let mut datetime = start;
+
+    while datetime <= end {
+        for timer0 in timer0.clone() {
+            for keys in keys.iter() {
+                let seed = gen5_seed(profile, &datetime, timer0, *keys);
+                if predicate(seed) {
+                    times.push(Gen5SeedTime {
+                        datetime,
+                        timer0,
+                        keys: *keys,
+                        seed,
+                    });
+                }
+            }
+        }
+        datetime = datetime.next_second();
+    }
+
+    times
+}
+
+/// A seed time whose IVs match a filter at one of the searched frames
+pub struct Gen5IvResult {
+    pub time: Gen5SeedTime,
+    pub iv_frame: IvFrame,
+}
+
+impl fmt::Debug for Gen5IvResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} frame {}: {:?}",
+            self.time, self.iv_frame.frame, self.iv_frame.ivs
+        )
+    }
+}
+
+/// Searches every second between start and end inclusive, each Timer0 value and each key combination,
+/// for seeds whose IVs match the stat filters of the filter at any of the given mersenne twister frames.
+pub fn gen5_iv_seed_times(
+    profile: &Gen5Profile,
+    start: DateTime,
+    end: DateTime,
+    timer0: RangeInclusive<u16>,
+    keys: &[u16],
+    frames: RangeInclusive<usize>,
+    filter: &Filter,
+) -> Vec<Gen5IvResult> {
+    let mut results = Vec::new();
+
+    for time in gen5_seed_times(profile, start, end, timer0, keys, |_| true) {
+        for iv_frame in gen5_iv_frames(time.seed, frames.clone()) {
+            if filter.matches_ivs(&iv_frame.ivs) {
+                results.push(Gen5IvResult { time, iv_frame });
+            }
+        }
+    }
+
+    results
+}
+
+/// Encodes a number below 100 as binary coded decimal
+fn bcd(value: u16) -> u16 {
+    ((value / 10) << 4) | (value % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{StatComparison, StatFilter};
+    use crate::Profile;
+
+    #[test]
+    fn test_gen5_seed_known_answer() -> Result<(), String> {
+        let profile = Gen5Profile::new(NAZO_BLACK_EN, 0x0009BF123456, 0x60, 6, 5, false);
+        let datetime = DateTime::new(2011, 3, 6, 13, 0, 59);
+
+        // nazo, VCount and Timer0, MAC with GxStat and VFrame, date and Sunday, 13:00:59 with the PM flag, then A held
+        let expected = [
+            0x105F2102,
+            0x0C602102,
+            0x0C602102,
+            0x58602102,
+            0x58602102,
+            0x790C6000,
+            0x00003456,
+            0x14BF0905,
+            0x11030600,
+            0x53005900,
+            0x00000000,
+            0x00000000,
+            0xFE2F0000u32,
+        ];
+        assert_eq!(
+            seed_message(&profile, &datetime, 0xC79, 0x1)[..13],
+            expected
+        );
+
+        // the SHA-1 of the message, advanced once through the 64-bit lcrng
+        assert_eq!(
+            gen5_seed(&profile, &datetime, 0xC79, 0x1),
+            0xAC63ECC1F7D65256
+        );
+        let morning = DateTime::new(2011, 3, 6, 9, 0, 0);
+        assert_eq!(gen5_seed(&profile, &morning, 0xC79, 0), 0x6DB3ADD823BCFF38);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen5_seed_search_finds_target() -> Result<(), String> {
+        let profile = Gen5Profile::new(NAZO_BLACK_EN, 0x0009BF123456, 0x60, 6, 5, false);
+        let target_time = DateTime::new(2011, 3, 6, 13, 0, 59);
+        let target = gen5_seed(&profile, &target_time, 0xC79, 0x1);
+
+        let start = DateTime::new(2011, 3, 6, 13, 0, 55);
+        let end = DateTime::new(2011, 3, 6, 13, 1, 5);
+        let times = gen5_seed_times(&profile, start, end, 0xC78..=0xC7A, &[0x0, 0x1], |seed| {
+            seed == target
+        });
+
+        assert_eq!(times.len(), 1);
+        assert!(times[0].datetime == target_time);
+        assert_eq!((times[0].timer0, times[0].keys), (0xC79, 0x1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen5_iv_search_reports_time() -> Result<(), String> {
+        let profile = Gen5Profile::new(NAZO_BLACK_EN, 0x0009BF123456, 0x60, 6, 5, false);
+        let target_time = DateTime::new(2011, 3, 6, 13, 0, 59);
+        let seed = gen5_seed(&profile, &target_time, 0xC79, 0x1);
+        let target = &gen5_iv_frames(seed, 3..=3)[0].ivs;
+
+        let filter = Filter::new(&Profile::new(0, 0))
+            .with_stat(StatFilter::HP(StatComparison::EqualTo(target.hp)))
+            .with_stat(StatFilter::Attack(StatComparison::EqualTo(target.atk)))
+            .with_stat(StatFilter::Defense(StatComparison::EqualTo(target.def)))
+            .with_stat(StatFilter::SpecialAttack(StatComparison::EqualTo(
+                target.spa,
+            )))
+            .with_stat(StatFilter::SpecialDefense(StatComparison::EqualTo(
+                target.spd,
+            )))
+            .with_stat(StatFilter::Speed(StatComparison::EqualTo(target.spe)));
+
+        let results = gen5_iv_seed_times(
+            &profile,
+            target_time,
+            target_time,
+            0xC79..=0xC79,
+            &[0x0, 0x1],
+            1..=5,
+            &filter,
+        );
+        assert!(results
+            .iter()
+            .any(|result| result.time.keys == 0x1 && result.iv_frame.frame == 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen5_seed_depends_on_pm_flag() -> Result<(), String> {
+        let ds = Gen5Profile::new(NAZO_WHITE_EN, 0x0009BF123456, 0x60, 6, 5, false);
+        let three_ds = Gen5Profile::new(NAZO_WHITE_EN, 0x0009BF123456, 0x60, 6, 5, true);
+
+        let morning = DateTime::new(2011, 3, 6, 9, 0, 0);
+        assert_eq!(
+            gen5_seed(&ds, &morning, 0xC79, 0),
+            gen5_seed(&three_ds, &morning, 0xC79, 0)
+        );
+        let afternoon = DateTime::new(2011, 3, 6, 21, 0, 0);
+        assert_ne!(
+            gen5_seed(&ds, &afternoon, 0xC79, 0),
+            gen5_seed(&three_ds, &afternoon, 0xC79, 0)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+mod datetime;
+mod gen4;
+mod gen5;
+mod sha1;
+mod verify;
+
+pub use self::datetime::DateTime;
+pub use self::gen4::{gen4_seed, gen4_seed_times, SeedTime};
+pub use self::gen5::{
+    gen5_iv_seed_times, gen5_seed, gen5_seed_times, Gen5IvResult, Gen5Profile, Gen5SeedTime,
+    NAZO_BLACK_EN, NAZO_WHITE_EN,
+};
+pub use self::verify::{
+    coin_flips, elm_calls, seed_window, verify_coin_flips, verify_elm_calls, CoinFlip, ElmCall,
+};
@@ -0,0 +1,67 @@
+/// Hashes a single 512-bit block of already padded message words with SHA-1, returning the five words of the digest.
+///
+/// Gen 5 only ever hashes one block, so the general purpose message padding and chaining of SHA-1 is left out.
+pub(crate) fn sha1_block(block: &[u32; 16]) -> [u32; 5] {
+    let mut w = [0u32; 80];
+    w[..16].copy_from_slice(block);
+    for i in 16..80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+    }
+
+    let h = [
+        0x67452301u32,
+        0xEFCDAB89u32,
+        0x98BADCFEu32,
+        0x10325476u32,
+        0xC3D2E1F0u32,
+    ];
+    let [mut a, mut b, mut c, mut d, mut e] = h;
+
+    for (i, word) in w.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => ((b & c) | (!b & d), 0x5A827999u32),
+            20..=39 => (b ^ c ^ d, 0x6ED9EBA1u32),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDCu32),
+            _ => (b ^ c ^ d, 0xCA62C1D6u32),
+        };
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(*word);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    [
+        h[0].wrapping_add(a),
+        h[1].wrapping_add(b),
+        h[2].wrapping_add(c),
+        h[3].wrapping_add(d),
+        h[4].wrapping_add(e),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha1_abc() -> Result<(), String> {
+        // "abc" followed by the padding bit and a length of 24 bits
+        let mut block = [0u32; 16];
+        block[0] = 0x61626380;
+        block[15] = 24;
+
+        let digest = sha1_block(&block);
+        assert_eq!(
+            digest,
+            [0xA9993E36, 0x4706816A, 0xBA3E2571, 0x7850C26C, 0x9CD0D89D]
+        );
+        Ok(())
+    }
+}
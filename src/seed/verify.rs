@@ -0,0 +1,141 @@
+use std::ops::RangeInclusive;
+
+use crate::generators::{LinearCongruential, MersenneTwister};
+use crate::seed::{gen4_seed, DateTime, SeedTime};
+
+/// The result of flipping the coin in the Pokétch coin toss app in Diamond, Pearl and Platinum
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CoinFlip {
+    Heads,
+    Tails,
+}
+
+/// The three possible texts of Professor Elm's phone calls in HeartGold and SoulSilver, which Irwin's calls follow as well.
+/// Named by the letters RNG tools display them as.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ElmCall {
+    E,
+    K,
+    P,
+}
+
+/// Generates the first coin flips made after loading a Diamond, Pearl or Platinum save with the given seed.
+/// Each flip is the lowest bit of the next number from the mersenne twister.
+pub fn coin_flips(seed: u32, count: usize) -> Vec<CoinFlip> {
+    let mut mt = MersenneTwister::new(seed);
+    (0..count)
+        .map(|_| {
+            if mt.next_u32() & 1 == 0 {
+                CoinFlip::Tails
+            } else {
+                CoinFlip::Heads
+            }
+        })
+        .collect()
+}
+
+/// Generates the first calls made by Elm after loading a HeartGold or SoulSilver save with the given seed.
+/// Each call is chosen by the next random number of the LCRNG modulo 3.
+pub fn elm_calls(seed: u32, count: usize) -> Vec<ElmCall> {
+    let mut rng = LinearCongruential::new(seed);
+    (0..count)
+        .map(|_| match rng.next_u16() % 3 {
+            0 => ElmCall::E,
+            1 => ElmCall::K,
+            _ => ElmCall::P,
+        })
+        .collect()
+}
+
+/// Lists the seeds produced by loading the game within the given number of seconds before or after the given date and time, with any of the given delays.
+/// Passing `0..=0` as the seconds only varies the delay.
+pub fn seed_window(
+    datetime: &DateTime,
+    seconds: RangeInclusive<i32>,
+    delays: RangeInclusive<u32>,
+) -> Vec<SeedTime> {
+    let mut start = *datetime;
+    for _ in *seconds.start()..0 {
+        start = start.previous_second();
+    }
+    for _ in 0..*seconds.start() {
+        start = start.next_second();
+    }
+
+    let mut times = Vec::new();
+    let mut second = start;
+    for _ in seconds {
+        times.extend(delays.clone().map(|delay| SeedTime {
+            datetime: second,
+            delay,
+        }));
+        second = second.next_second();
+    }
+    times
+}
+
+/// Keeps the candidate seeds whose first coin flips are the ones observed
+pub fn verify_coin_flips(candidates: Vec<SeedTime>, observed: &[CoinFlip]) -> Vec<SeedTime> {
+    candidates
+        .into_iter()
+        .filter(|time| {
+            coin_flips(gen4_seed(&time.datetime, time.delay), observed.len()) == observed
+        })
+        .collect()
+}
+
+/// Keeps the candidate seeds whose first Elm calls are the ones observed
+pub fn verify_elm_calls(candidates: Vec<SeedTime>, observed: &[ElmCall]) -> Vec<SeedTime> {
+    candidates
+        .into_iter()
+        .filter(|time| elm_calls(gen4_seed(&time.datetime, time.delay), observed.len()) == observed)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elm_calls() -> Result<(), String> {
+        // 0x01DB, 0x7B06, 0x5233, 0xE470 modulo 3
+        let calls = elm_calls(0x1A56B091u32, 4);
+        assert_eq!(calls, vec![ElmCall::K, ElmCall::E, ElmCall::K, ElmCall::K]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_narrows_window() -> Result<(), String> {
+        let datetime = DateTime::new(2010, 3, 14, 10, 30, 21);
+        let target = gen4_seed(&datetime, 620);
+
+        let observed = coin_flips(target, 10);
+        let verified = verify_coin_flips(seed_window(&datetime, 0..=0, 600..=640), &observed);
+        assert!(verified.iter().any(|time| time.delay == 620));
+        assert!(verified.len() < 41);
+
+        let observed = elm_calls(target, 10);
+        let verified = verify_elm_calls(seed_window(&datetime, 0..=0, 600..=640), &observed);
+        assert!(verified.iter().any(|time| time.delay == 620));
+        assert!(verified.len() < 41);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_window_seconds() -> Result<(), String> {
+        // a load one second off the planned time is still found
+        let planned = DateTime::new(2010, 3, 14, 10, 30, 21);
+        let actual = DateTime::new(2010, 3, 14, 10, 30, 20);
+        let target = gen4_seed(&actual, 620);
+
+        let window = seed_window(&planned, -2..=2, 600..=640);
+        assert_eq!(window.len(), 5 * 41);
+        assert!(window[0].datetime == DateTime::new(2010, 3, 14, 10, 30, 19));
+
+        let verified = verify_coin_flips(window, &coin_flips(target, 10));
+        assert!(verified
+            .iter()
+            .any(|time| time.datetime == actual && time.delay == 620));
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use crate::filter::{StatComparison, StatFilter};
+use crate::pokemon::{IndividualValues, NATURES};
+use crate::search::SearchResult;
+use crate::{Filter, Profile};
+
+/// Stat names in the order used by the IVs line of a Showdown set
+const STAT_NAMES: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+#[derive(Debug, PartialEq)]
+pub enum ShowdownError {
+    /// A nature line names a nature which does not exist
+    InvalidNature(String),
+    /// An entry of the IVs line is not a stat name preceded by a value between 0 and 31
+    InvalidIv(String),
+}
+
+impl fmt::Display for ShowdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowdownError::InvalidNature(nature) => write!(f, "unknown nature: {}", nature),
+            ShowdownError::InvalidIv(iv) => write!(f, "invalid IV: {}", iv),
+        }
+    }
+}
+
+impl std::error::Error for ShowdownError {}
+
+/// Formats the pokemon of a search result as a Showdown set, for pasting into team builders.
+///
+/// The species is not known to masuda, so it is passed in along with the species' abilities, which are indexed by the ability bit of the PID.
+/// Species with a single ability have it chosen regardless of the PID.
+/// As in Showdown's own exports, IVs of 31 are left off the IVs line.
+pub fn export(result: &SearchResult, species: &str, abilities: &[&str]) -> String {
+    let pokemon = &result.pokemon;
+    let mut set = format!("{}\n", species);
+
+    if !abilities.is_empty() {
+        let ability = abilities[pokemon.get_ability() as usize % abilities.len()];
+        set.push_str(&format!("Ability: {}\n", ability));
+    }
+    set.push_str(&format!("{} Nature\n", pokemon.get_nature()));
+
+    let ivs: Vec<String> = iv_values(&pokemon.ivs)
+        .iter()
+        .zip(STAT_NAMES.iter())
+        .filter(|(iv, _)| **iv != 31)
+        .map(|(iv, stat)| format!("{} {}", iv, stat))
+        .collect();
+    if !ivs.is_empty() {
+        set.push_str(&format!("IVs: {}\n", ivs.join(" / ")));
+    }
+
+    set.push_str(&format!(
+        "- Hidden Power [{}]\n",
+        pokemon.ivs.get_hidden_power_type()
+    ));
+    set
+}
+
+/// Parses a Showdown set into a filter matching its nature and exact IVs, so a search finds frames producing that set.
+///
+/// Showdown treats IVs missing from the IVs line as 31, so every stat is constrained even if the set has no IVs line.
+/// Lines other than the nature and IVs, such as the species, item and moves, are ignored.
+pub fn import(profile: &Profile, set: &str) -> Result<Filter, ShowdownError> {
+    let mut filter = Filter::new(profile);
+    let mut ivs = [31u8; 6];
+
+    for line in set.lines().map(str::trim) {
+        if let Some(nature_name) = line.strip_suffix(" Nature") {
+            let nature = NATURES
+                .iter()
+                .find(|nature| nature.to_string() == nature_name)
+                .ok_or_else(|| ShowdownError::InvalidNature(nature_name.to_string()))?;
+            filter = filter.with_nature(*nature);
+        } else if let Some(iv_line) = line.strip_prefix("IVs:") {
+            for entry in iv_line.split('/').map(str::trim) {
+                let invalid = || ShowdownError::InvalidIv(entry.to_string());
+                let mut parts = entry.split_whitespace();
+                let value = parts
+                    .next()
+                    .and_then(|value| value.parse::<u8>().ok())
+                    .filter(|value| *value <= 31)
+                    .ok_or_else(invalid)?;
+                let stat = parts
+                    .next()
+                    .and_then(|name| STAT_NAMES.iter().position(|stat| *stat == name))
+                    .ok_or_else(invalid)?;
+                ivs[stat] = value;
+            }
+        }
+    }
+
+    let [hp, atk, def, spa, spd, spe] = ivs;
+    Ok(filter
+        .with_stat(StatFilter::HP(StatComparison::EqualTo(hp)))
+        .with_stat(StatFilter::Attack(StatComparison::EqualTo(atk)))
+        .with_stat(StatFilter::Defense(StatComparison::EqualTo(def)))
+        .with_stat(StatFilter::SpecialAttack(StatComparison::EqualTo(spa)))
+        .with_stat(StatFilter::SpecialDefense(StatComparison::EqualTo(spd)))
+        .with_stat(StatFilter::Speed(StatComparison::EqualTo(spe))))
+}
+
+fn iv_values(ivs: &IndividualValues) -> [u8; 6] {
+    [ivs.hp, ivs.atk, ivs.def, ivs.spa, ivs.spd, ivs.spe]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::Pokemon;
+
+    #[test]
+    fn test_export() -> Result<(), String> {
+        // a Jolly pokemon with the second ability
+        let pokemon = Pokemon::new(13, IndividualValues::new(31, 31, 31, 30, 31, 20));
+        let result = SearchResult {
+            pokemon,
+            frame: 0,
+            advances: 4,
+            slot: None,
+            level: None,
+        };
+
+        let set = export(&result, "Jirachi", &["Serene Grace"]);
+        assert_eq!(
+            set,
+            "Jirachi\nAbility: Serene Grace\nJolly Nature\nIVs: 30 SpA / 20 Spe\n- Hidden Power [Water]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_import() -> Result<(), String> {
+        let profile = Profile::new(10101, 12345);
+        let set = "Salamence @ Choice Band\nAbility: Intimidate\nEVs: 252 Atk / 252 Spe\nAdamant Nature\nIVs: 0 SpA\n- Dragon Claw";
+        let filter = import(&profile, set).unwrap();
+
+        let adamant = Pokemon::new(3, IndividualValues::new(31, 31, 31, 0, 31, 31));
+        assert!(filter.matches(&adamant));
+        let jolly = Pokemon::new(13, IndividualValues::new(31, 31, 31, 0, 31, 31));
+        assert!(!filter.matches(&jolly));
+        let imperfect = Pokemon::new(3, IndividualValues::new(31, 31, 31, 0, 31, 30));
+        assert!(!filter.matches(&imperfect));
+
+        assert_eq!(
+            import(&profile, "Calmer Nature").err(),
+            Some(ShowdownError::InvalidNature("Calmer".to_string()))
+        );
+        assert_eq!(
+            import(&profile, "IVs: 32 HP").err(),
+            Some(ShowdownError::InvalidIv("32 HP".to_string()))
+        );
+        Ok(())
+    }
+}
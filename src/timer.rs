@@ -0,0 +1,306 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::generators::Method;
+use crate::pokemon::Pokemon;
+use crate::search::SearchResult;
+use crate::Searcher;
+
+/// Number of beeps, half a second apart, ending each stage of the countdown
+const BEEP_COUNT: u32 = 5;
+const BEEP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The consoles the games run on, each of which draws frames at its own rate
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Console {
+    Gba,
+    Nds,
+    GameCubeNtsc,
+    GameCubePal,
+}
+
+impl Console {
+    pub fn fps(&self) -> f64 {
+        match self {
+            Console::Gba => 59.7275,
+            Console::Nds => 59.8261,
+            Console::GameCubeNtsc => 59.94,
+            Console::GameCubePal => 50.0,
+        }
+    }
+
+    /// Converts a number of frames into the wall clock time the console takes to draw them
+    pub fn frames_to_duration(&self, frames: u64) -> Duration {
+        Duration::from_secs_f64(frames as f64 / self.fps())
+    }
+
+    /// Converts a wall clock duration into the number of frames drawn in that time, rounded to the nearest frame
+    pub fn duration_to_frames(&self, duration: Duration) -> u64 {
+        (duration.as_secs_f64() * self.fps()).round() as u64
+    }
+}
+
+/// One stage of a timer, such as waiting for the seed or advancing to the target frame
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Stage {
+    /// A stage of fixed length, for example the time to hold before pressing continue to hit a seed
+    Duration(Duration),
+    /// A stage lasting the given number of frames
+    Frames(u64),
+}
+
+/// A seed near the targeted one which a missed seed timing may have landed on, such as the neighbouring FireRed and LeafGreen seeds
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NearbySeed {
+    pub seed: u32,
+    /// How much later than the targeted seed this seed is hit, in milliseconds, negative when it is hit earlier
+    pub offset_ms: i64,
+}
+
+/// Where a missed attempt actually landed, and the timer corrected to hit the target next time
+pub struct Calibration {
+    pub seed: u32,
+    pub frame: usize,
+    pub timer: Timer,
+}
+
+/// Finds the seed and frame a missed attempt landed on from the pokemon it produced, and corrects the timer accordingly.
+///
+/// The frames within `frame_window` of the target are searched from the target seed and from each of the nearby seeds,
+/// preferring the match closest to the target. The first stage of the timer is taken to time the seed and the last stage to advance frames,
+/// so a timer with a single stage only has its frames corrected.
+/// Returns `None` when the timer has no stages, or when the pokemon is not generated by any of the searched frames.
+pub fn calibrate_from_pokemon(
+    timer: &Timer,
+    method: Method,
+    target_seed: u32,
+    target: &SearchResult,
+    nearby_seeds: &[NearbySeed],
+    observed: &Pokemon,
+    frame_window: usize,
+) -> Option<Calibration> {
+    if timer.stages.is_empty() {
+        return None;
+    }
+
+    let first_frame = target.frame.saturating_sub(frame_window);
+    let candidates = std::iter::once(NearbySeed {
+        seed: target_seed,
+        offset_ms: 0,
+    })
+    .chain(nearby_seeds.iter().copied());
+
+    let mut best: Option<(NearbySeed, usize)> = None;
+    for candidate in candidates {
+        let mut searcher =
+            Searcher::with_seed(method, candidate.seed, target.frame + frame_window + 1);
+        let hit = searcher
+            .search(None)
+            .into_iter()
+            .skip(first_frame)
+            .filter(|result| {
+                result.pokemon.pid == observed.pid && result.pokemon.ivs == observed.ivs
+            })
+            .min_by_key(|result| (result.frame as i64 - target.frame as i64).abs());
+
+        if let Some(hit) = hit {
+            let distance = |seed: &NearbySeed, frame: usize| {
+                (
+                    seed.offset_ms.abs(),
+                    (frame as i64 - target.frame as i64).abs(),
+                )
+            };
+            let closer = match &best {
+                Some((seed, frame)) => distance(&candidate, hit.frame) < distance(seed, *frame),
+                None => true,
+            };
+            if closer {
+                best = Some((candidate, hit.frame));
+            }
+        }
+    }
+
+    let (seed, frame) = best?;
+    let mut corrected = timer.clone();
+    let last_stage = corrected.stages.len() - 1;
+    if last_stage > 0 && seed.offset_ms != 0 {
+        let offset = Duration::from_millis(seed.offset_ms.unsigned_abs());
+        let offset_frames = timer.console.duration_to_frames(offset) as i64;
+        corrected.calibrations[0] -= offset_frames * seed.offset_ms.signum();
+    }
+    corrected.calibrate(last_stage, target.frame as u64, frame as u64)?;
+    Some(Calibration {
+        seed: seed.seed,
+        frame,
+        timer: corrected,
+    })
+}
+
+/// A timer made of consecutive stages, each of which can be calibrated after a missed attempt.
+///
+/// A typical Gen 3 timer has a first stage timing the seed, and a second stage advancing from it to the target frame.
+#[derive(Clone)]
+pub struct Timer {
+    console: Console,
+    stages: Vec<Stage>,
+    /// Calibration of each stage in frames, added to its length
+    calibrations: Vec<i64>,
+}
+
+impl Timer {
+    pub fn new(console: Console) -> Self {
+        Timer {
+            console,
+            stages: Vec::new(),
+            calibrations: Vec::new(),
+        }
+    }
+
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        self.stages.push(stage);
+        self.calibrations.push(0);
+        self
+    }
+
+    /// Adjusts a stage after hitting the wrong frame. Hitting an earlier frame than the target lengthens the stage by the difference,
+    /// and hitting a later frame shortens it. Calibrations accumulate over several attempts.
+    /// Returns the new calibration of the stage, or `None` when the timer has no such stage.
+    pub fn calibrate(&mut self, stage: usize, target_frame: u64, hit_frame: u64) -> Option<i64> {
+        let calibration = self.calibrations.get_mut(stage)?;
+        *calibration += target_frame as i64 - hit_frame as i64;
+        Some(*calibration)
+    }
+
+    /// The calibration applied to a stage so far, in frames, or `None` when the timer has no such stage
+    pub fn calibration(&self, stage: usize) -> Option<i64> {
+        self.calibrations.get(stage).copied()
+    }
+
+    /// The length of each stage once its calibration is applied. A stage calibrated below zero lasts no time at all.
+    pub fn stage_durations(&self) -> Vec<Duration> {
+        self.stages
+            .iter()
+            .zip(self.calibrations.iter())
+            .map(|(stage, calibration)| {
+                let base = match stage {
+                    Stage::Duration(duration) => duration.as_secs_f64(),
+                    Stage::Frames(frames) => *frames as f64 / self.console.fps(),
+                };
+                let calibrated = base + *calibration as f64 / self.console.fps();
+                Duration::from_secs_f64(calibrated.max(0.0))
+            })
+            .collect()
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.stage_durations().iter().sum()
+    }
+
+    /// Runs the timer in the terminal, printing the remaining time of each stage and beeping for the last few half seconds of it.
+    pub fn countdown<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for (i, duration) in self.stage_durations().into_iter().enumerate() {
+            writeln!(out, "stage {}: {:.3}s", i + 1, duration.as_secs_f64())?;
+            let end = Instant::now() + duration;
+
+            let beeps_start = duration.saturating_sub(BEEP_INTERVAL * BEEP_COUNT);
+            thread::sleep(beeps_start);
+            while let Some(remaining) = end.checked_duration_since(Instant::now()) {
+                if remaining.is_zero() {
+                    break;
+                }
+                write!(out, "\x07{:.1}s\r", remaining.as_secs_f64())?;
+                out.flush()?;
+                thread::sleep(remaining.min(BEEP_INTERVAL));
+            }
+            writeln!(out, "\x07stage {} done", i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_to_duration() -> Result<(), String> {
+        let duration = Console::Gba.frames_to_duration(597275);
+        assert_eq!(duration.as_secs_f64().round(), 10000.0);
+        assert_eq!(
+            Console::Nds.duration_to_frames(Duration::from_secs(10)),
+            598
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_calibration() -> Result<(), String> {
+        let mut timer = Timer::new(Console::Gba)
+            .with_stage(Stage::Duration(Duration::from_millis(5000)))
+            .with_stage(Stage::Frames(1000));
+
+        assert_eq!(timer.calibrate(1, 1000, 990), Some(10));
+        assert_eq!(timer.calibration(1), Some(10));
+        let durations = timer.stage_durations();
+        assert_eq!(durations[0], Duration::from_millis(5000));
+        assert_eq!(Console::Gba.duration_to_frames(durations[1]), 1010);
+
+        assert_eq!(timer.calibrate(1, 1000, 1015), Some(-5));
+        assert_eq!(timer.calibrate(2, 1000, 1015), None);
+        assert_eq!(timer.calibration(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_calibrate_from_pokemon() -> Result<(), String> {
+        let timer = Timer::new(Console::Gba)
+            .with_stage(Stage::Duration(Duration::from_millis(30000)))
+            .with_stage(Stage::Frames(600));
+        let target = Searcher::with_seed(Method::One, 0x1234, 601)
+            .search(None)
+            .remove(600);
+
+        // hitting frame 596 of the targeted seed lengthens the frame stage by 4 frames
+        let observed = Searcher::with_seed(Method::One, 0x1234, 597)
+            .search(None)
+            .remove(596)
+            .pokemon;
+        let calibration =
+            calibrate_from_pokemon(&timer, Method::One, 0x1234, &target, &[], &observed, 10)
+                .unwrap();
+        assert_eq!((calibration.seed, calibration.frame), (0x1234, 596));
+        assert_eq!(calibration.timer.calibration(0), Some(0));
+        assert_eq!(calibration.timer.calibration(1), Some(4));
+
+        // hitting a seed 100ms late shortens the seed stage by 6 frames
+        let late = NearbySeed {
+            seed: 0x5678,
+            offset_ms: 100,
+        };
+        let observed = Searcher::with_seed(Method::One, 0x5678, 601)
+            .search(None)
+            .remove(600)
+            .pokemon;
+        let calibration =
+            calibrate_from_pokemon(&timer, Method::One, 0x1234, &target, &[late], &observed, 10)
+                .unwrap();
+        assert_eq!((calibration.seed, calibration.frame), (0x5678, 600));
+        assert_eq!(calibration.timer.calibration(0), Some(-6));
+        assert_eq!(calibration.timer.calibration(1), Some(0));
+
+        // a timer without stages has nothing to correct
+        let empty = Timer::new(Console::Gba);
+        assert!(
+            calibrate_from_pokemon(&empty, Method::One, 0x1234, &target, &[], &observed, 10)
+                .is_none()
+        );
+
+        let missing = Pokemon::new(0, Default::default());
+        assert!(
+            calibrate_from_pokemon(&timer, Method::One, 0x1234, &target, &[], &missing, 10)
+                .is_none()
+        );
+        Ok(())
+    }
+}